@@ -0,0 +1,69 @@
+use std::hint::black_box;
+
+use criterion::{ criterion_group, criterion_main, Criterion };
+use glam::{ IVec3, Vec3 };
+use spriso::IsoProjection;
+
+fn tiles() -> Vec<IVec3> {
+    (0..32).flat_map(|z| (0..64).flat_map(move |y| (0..32).map(move |x| IVec3::new(x, y, z)))).collect()
+}
+
+fn world_to_screen(c: &mut Criterion) {
+    let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    let src: Vec<IVec3> = tiles();
+    let mut dst: Vec<Vec3> = vec![Vec3::ZERO; src.len()];
+
+    let mut group = c.benchmark_group("world_to_screen");
+    group.bench_function("scalar", |b| {
+        b.iter(|| {
+            for (world_pos, screen_pos) in black_box(&src).iter().zip(dst.iter_mut()) {
+                *screen_pos = proj.world_to_screen(*world_pos);
+            }
+        })
+    });
+    group.bench_function("slice", |b| {
+        b.iter(|| proj.world_to_screen_slice(black_box(&src), &mut dst))
+    });
+    group.finish();
+}
+
+fn screen_to_world(c: &mut Criterion) {
+    let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    let src: Vec<Vec3> = tiles().into_iter().map(|pos| proj.world_to_screen(pos)).collect();
+    let mut dst: Vec<IVec3> = vec![IVec3::ZERO; src.len()];
+
+    let mut group = c.benchmark_group("screen_to_world");
+    group.bench_function("scalar", |b| {
+        b.iter(|| {
+            for (screen_pos, world_pos) in black_box(&src).iter().zip(dst.iter_mut()) {
+                *world_pos = proj.screen_to_world(*screen_pos);
+            }
+        })
+    });
+    group.bench_function("slice", |b| {
+        b.iter(|| proj.screen_to_world_slice(black_box(&src), &mut dst))
+    });
+    group.finish();
+}
+
+#[cfg(feature = "rayon")]
+fn world_to_screen_large(c: &mut Criterion) {
+    let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    let src: Vec<IVec3> = (0..64).flat_map(|z| (0..512).flat_map(move |y| (0..512).map(move |x| IVec3::new(x, y, z)))).collect();
+    let mut dst: Vec<Vec3> = vec![Vec3::ZERO; src.len()];
+
+    let mut group = c.benchmark_group("world_to_screen_large");
+    group.bench_function("slice", |b| {
+        b.iter(|| proj.world_to_screen_slice(black_box(&src), &mut dst))
+    });
+    group.bench_function("par", |b| {
+        b.iter(|| proj.world_to_screen_par(black_box(&src), &mut dst))
+    });
+    group.finish();
+}
+
+#[cfg(not(feature = "rayon"))]
+criterion_group!(benches, world_to_screen, screen_to_world);
+#[cfg(feature = "rayon")]
+criterion_group!(benches, world_to_screen, screen_to_world, world_to_screen_large);
+criterion_main!(benches);
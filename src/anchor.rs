@@ -0,0 +1,18 @@
+/// The point of a tile's diamond that screen positions refer to.
+///
+/// Sprites are usually drawn from a fixed point of their image, so picking the
+/// matching anchor makes [`IsoProjection::world_to_screen`](crate::IsoProjection::world_to_screen)
+/// return the sprite's placement directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Anchor {
+    /// The top vertex of the diamond.
+    Top,
+
+    /// The center of the diamond, which is where the projection matrix maps a tile.
+    #[default]
+    Center,
+
+    /// The bottom vertex of the diamond.
+    Bottom,
+}
@@ -0,0 +1,91 @@
+use approx::{ AbsDiffEq, RelativeEq };
+use crate::{ IsoProjection, Scalar };
+
+impl<S> AbsDiffEq for IsoProjection<S>
+where
+    S: Scalar + AbsDiffEq<Epsilon = S>,
+    S::Vec2: AbsDiffEq<Epsilon = S>,
+    S::Mat2: AbsDiffEq<Epsilon = S>,
+{
+    type Epsilon = S;
+
+    fn default_epsilon() -> S {
+        S::default_epsilon()
+    }
+
+    /// Compares the configuration and matrix of two projections, ignoring the
+    /// precomputed inverses since they follow from the rest.
+    fn abs_diff_eq(&self, other: &Self, epsilon: S) -> bool {
+        self.half_tw.abs_diff_eq(&other.half_tw, epsilon)
+            && self.half_th.abs_diff_eq(&other.half_th, epsilon)
+            && self.iso_matrix_2d.abs_diff_eq(&other.iso_matrix_2d, epsilon)
+            && self.z_scale.abs_diff_eq(&other.z_scale, epsilon)
+            && self.origin.abs_diff_eq(&other.origin, epsilon)
+            && self.parallax.abs_diff_eq(&other.parallax, epsilon)
+            && self.layer_offset.abs_diff_eq(&other.layer_offset, epsilon)
+            && self.zoom.abs_diff_eq(&other.zoom, epsilon)
+            && self.layout == other.layout
+            && self.orientation == other.orientation
+            && self.y_axis == other.y_axis
+            && self.mirror_x == other.mirror_x
+            && self.anchor == other.anchor
+    }
+}
+
+impl<S> RelativeEq for IsoProjection<S>
+where
+    S: Scalar + RelativeEq<Epsilon = S>,
+    S::Vec2: RelativeEq<Epsilon = S>,
+    S::Mat2: RelativeEq<Epsilon = S>,
+{
+    fn default_max_relative() -> S {
+        S::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: S, max_relative: S) -> bool {
+        self.half_tw.relative_eq(&other.half_tw, epsilon, max_relative)
+            && self.half_th.relative_eq(&other.half_th, epsilon, max_relative)
+            && self.iso_matrix_2d.relative_eq(&other.iso_matrix_2d, epsilon, max_relative)
+            && self.z_scale.relative_eq(&other.z_scale, epsilon, max_relative)
+            && self.origin.relative_eq(&other.origin, epsilon, max_relative)
+            && self.parallax.relative_eq(&other.parallax, epsilon, max_relative)
+            && self.layer_offset.relative_eq(&other.layer_offset, epsilon, max_relative)
+            && self.zoom.relative_eq(&other.zoom, epsilon, max_relative)
+            && self.layout == other.layout
+            && self.orientation == other.orientation
+            && self.y_axis == other.y_axis
+            && self.mirror_x == other.mirror_x
+            && self.anchor == other.anchor
+    }
+}
+
+impl<S> IsoProjection<S>
+where
+    S: Scalar + AbsDiffEq<Epsilon = S>,
+    S::Vec2: AbsDiffEq<Epsilon = S>,
+    S::Mat2: AbsDiffEq<Epsilon = S>,
+{
+    /// Returns whether two projections are equal up to an absolute difference of `eps`
+    /// in every value, and exactly equal in layout, orientation, y axis, mirroring and anchor.
+    ///
+    /// This is [`AbsDiffEq::abs_diff_eq`], for projections recomputed through a
+    /// different path that aren't bit for bit equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "approx")] {
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection<f64> = IsoProjection::from_dims(0.1 + 0.2, 14.0);
+    /// let other: IsoProjection<f64> = IsoProjection::from_dims(0.3, 14.0);
+    ///
+    /// assert_ne!(proj, other);
+    /// assert!(proj.approx_eq(&other, 1e-6));
+    /// approx::assert_relative_eq!(proj, other);
+    /// # }
+    /// ```
+    pub fn approx_eq(&self, other: &Self, eps: S) -> bool {
+        self.abs_diff_eq(other, eps)
+    }
+}
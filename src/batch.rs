@@ -0,0 +1,170 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use glam::IVec3;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use crate::{ IsoProjection, Layout, Scalar, Vector2, Vector3 };
+
+impl<S: Scalar> IsoProjection<S> {
+    /// Converts every grid position in `src` to its screen position in `dst`.
+    ///
+    /// Produces the same results as calling [`IsoProjection::world_to_screen`] on each
+    /// position, but reads the projection once for the whole slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// let src: [IVec3; 3] = [IVec3::new(0, 0, 0), IVec3::new(1, 2, 3), IVec3::new(-4, 5, -6)];
+    /// let mut dst: [Vec3; 3] = [Vec3::ZERO; 3];
+    /// proj.world_to_screen_slice(&src, &mut dst);
+    ///
+    /// assert_eq!(dst, src.map(|pos| proj.world_to_screen(pos)));
+    /// ```
+    pub fn world_to_screen_slice(&self, src: &[IVec3], dst: &mut [S::Vec3]) {
+        assert_eq!(src.len(), dst.len(), "source and destination slices must have equal lengths");
+
+        let iso_matrix_2d: S::Mat2 = self.iso_matrix_2d;
+        let zoom: S = self.zoom;
+        let origin: S::Vec2 = self.screen_offset();
+        let z_scale: S = self.z_scale;
+        let layout: Layout = self.layout;
+
+        for (world_pos, screen_pos) in src.iter().zip(dst.iter_mut()) {
+            let world_pos: IVec3 = layout.to_diamond(*world_pos);
+            let world_vec_2d: S::Vec2 = S::Vec2::new(S::from_i32(world_pos.x), S::from_i32(world_pos.y));
+            let screen_vec_2d: S::Vec2 = iso_matrix_2d * world_vec_2d * zoom + origin;
+
+            *screen_pos = S::Vec3::new(screen_vec_2d.x(), screen_vec_2d.y(), S::from_i32(world_pos.z) * z_scale * zoom);
+        }
+    }
+
+    /// Converts every grid position in `src` to its screen position in `dst` across the rayon thread pool.
+    ///
+    /// The slices are split into chunks that are each converted with
+    /// [`IsoProjection::world_to_screen_slice`], so the output is identical to it.
+    /// Worth it for loading very large maps at once; for a frame's worth of
+    /// tiles the sequential version is usually faster.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "rayon")] {
+    /// use glam::{ IVec3, Vec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// let src: Vec<IVec3> = (0..100_000).map(|i| IVec3::new(i % 300, i / 300, i % 7)).collect();
+    /// let mut par: Vec<Vec3> = vec![Vec3::ZERO; src.len()];
+    /// let mut seq: Vec<Vec3> = vec![Vec3::ZERO; src.len()];
+    /// proj.world_to_screen_par(&src, &mut par);
+    /// proj.world_to_screen_slice(&src, &mut seq);
+    ///
+    /// assert_eq!(par, seq);
+    /// # }
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn world_to_screen_par(&self, src: &[IVec3], dst: &mut [S::Vec3])
+    where
+        Self: Sync,
+        S::Vec3: Send,
+    {
+        const CHUNK_LEN: usize = 4096;
+
+        assert_eq!(src.len(), dst.len(), "source and destination slices must have equal lengths");
+
+        src.par_chunks(CHUNK_LEN)
+            .zip(dst.par_chunks_mut(CHUNK_LEN))
+            .for_each(|(src, dst)| self.world_to_screen_slice(src, dst));
+    }
+
+    /// Replaces the contents of `dst` with the screen position of every grid position in `src`.
+    ///
+    /// Produces the same results as [`IsoProjection::world_to_screen_slice`]. `dst` is
+    /// cleared first and only grows when its capacity is smaller than `src`, so
+    /// reusing the same buffer every frame doesn't allocate once it's warm.
+    ///
+    /// Returns the number of positions written, which is `src.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// let src: [IVec3; 3] = [IVec3::new(0, 0, 0), IVec3::new(1, 2, 3), IVec3::new(-4, 5, -6)];
+    /// let mut dst: Vec<Vec3> = Vec::with_capacity(8);
+    /// let buffer: *const Vec3 = dst.as_ptr();
+    ///
+    /// assert_eq!(proj.world_to_screen_into(&src, &mut dst), 3);
+    /// assert_eq!(proj.world_to_screen_into(&src[..2], &mut dst), 2);
+    ///
+    /// assert_eq!(dst, src[..2].iter().map(|pos| proj.world_to_screen(*pos)).collect::<Vec<Vec3>>());
+    /// assert_eq!(dst.as_ptr(), buffer);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn world_to_screen_into(&self, src: &[IVec3], dst: &mut Vec<S::Vec3>) -> usize {
+        dst.clear();
+        dst.resize(src.len(), S::Vec3::new(S::ZERO, S::ZERO, S::ZERO));
+        self.world_to_screen_slice(src, dst);
+
+        src.len()
+    }
+
+    /// Converts every screen position in `src` to its grid position in `dst`.
+    ///
+    /// Produces the same results as calling [`IsoProjection::screen_to_world`] on each
+    /// position, but reads the projection once for the whole slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// let src: [Vec3; 2] = [Vec3::new(28.0, 0.0, 14.0), Vec3::new(-3.0, 40.0, -29.0)];
+    /// let mut dst: [IVec3; 2] = [IVec3::ZERO; 2];
+    /// proj.screen_to_world_slice(&src, &mut dst);
+    ///
+    /// assert_eq!(dst, src.map(|pos| proj.screen_to_world(pos)));
+    /// ```
+    pub fn screen_to_world_slice(&self, src: &[S::Vec3], dst: &mut [IVec3]) {
+        assert_eq!(src.len(), dst.len(), "source and destination slices must have equal lengths");
+
+        let inv_iso_matrix_2d: S::Mat2 = self.inv_iso_matrix_2d;
+        let inv_zoom: S = self.inv_zoom;
+        let origin: S::Vec2 = self.screen_offset();
+        let inv_z_scale: S = self.inv_z_scale;
+        let layout: Layout = self.layout;
+
+        for (screen_pos, world_pos) in src.iter().zip(dst.iter_mut()) {
+            let screen_vec_2d: S::Vec2 = (S::Vec2::new(screen_pos.x(), screen_pos.y()) - origin) * inv_zoom;
+            let world_vec_2d: S::Vec2 = inv_iso_matrix_2d * screen_vec_2d;
+
+            *world_pos = layout.from_diamond(Self::nearest_tile(
+                S::Vec3::new(world_vec_2d.x(), world_vec_2d.y(), screen_pos.z() * inv_z_scale * inv_zoom)
+            ));
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use core::ops::Range;
+use bevy_transform::components::{ GlobalTransform, Transform };
+use glam::{ IVec3, Mat3, Vec2, Vec3 };
+use crate::IsoProjection;
+
+impl IsoProjection<f32> {
+    /// Returns the Bevy transform to render a tile at.
+    ///
+    /// The translation x/y is where the tile is drawn, with its elevation folded in
+    /// and y negated for [`YAxis::Down`](crate::YAxis::Down) since Bevy's y axis points up.
+    /// Projections with [`YAxis::Up`](crate::YAxis::Up) are used as is. The translation z is the
+    /// [`IsoProjection::depth_key`], so Bevy's 2D renderer draws tiles back to front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "bevy")] {
+    /// use bevy_transform::components::Transform;
+    /// use glam::{ IVec3, Vec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// let transform: Transform = proj.world_to_transform(IVec3::new(1, 0, 1));
+    ///
+    /// assert_eq!(transform.translation, Vec3::new(14.0, 7.0, 2.0));
+    /// assert!(proj.world_to_transform(IVec3::new(1, 1, 0)).translation.z > proj.world_to_transform(IVec3::ZERO).translation.z);
+    /// # }
+    /// ```
+    pub fn world_to_transform(&self, world_pos: IVec3) -> Transform {
+        let drawn_pos: Vec2 = self.drawn_position(world_pos);
+
+        Transform::from_xyz(drawn_pos.x, self.y_axis.orient(-drawn_pos.y), self.depth_key(world_pos))
+    }
+
+    /// Returns the grid position of a tile rendered with [`IsoProjection::world_to_transform`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "bevy")] {
+    /// use glam::IVec3;
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// let pos: IVec3 = IVec3::new(-6, 11, 3);
+    ///
+    /// assert_eq!(proj.transform_to_world(&proj.world_to_transform(pos)), pos);
+    /// # }
+    /// ```
+    pub fn transform_to_world(&self, transform: &Transform) -> IVec3 {
+        // The translation is affine in the diamond grid position, so build that map
+        // from the unit axes and invert it to recover all three coordinates at once.
+        let translate = |world_pos: Vec3| -> Vec3 {
+            let drawn_pos: Vec2 = self.drawn_screen_pos(self.project_f(world_pos, self.applied_origin()));
+
+            Vec3::new(drawn_pos.x, self.y_axis.orient(-drawn_pos.y), self.depth_key_f(world_pos))
+        };
+        let base: Vec3 = translate(Vec3::ZERO);
+        let linear: Mat3 = Mat3::from_cols(
+            translate(Vec3::X) - base,
+            translate(Vec3::Y) - base,
+            translate(Vec3::Z) - base
+        );
+        let world_pos: Vec3 = linear.inverse() * (transform.translation - base);
+
+        self.layout.from_diamond(Self::nearest_tile(world_pos))
+    }
+
+    /// Returns the top-most occupied tile under the cursor of a Bevy 2D camera.
+    ///
+    /// `cursor` is the window cursor position as Bevy reports it, in logical pixels
+    /// from the top-left corner of a viewport of `viewport_size`. It's moved through
+    /// `camera_transform` into Bevy world space, where tiles are placed by
+    /// [`IsoProjection::world_to_transform`], and then picked with
+    /// [`IsoProjection::pick_topmost`], so the projection's origin, zoom and each
+    /// level's elevation are accounted for. The camera is expected to use an
+    /// orthographic projection of one world unit per pixel: zoom it through its
+    /// transform's scale, or divide the cursor offset from the viewport center by
+    /// the projection's scale first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "bevy")] {
+    /// use bevy_transform::components::{ GlobalTransform, Transform };
+    /// use glam::{ IVec3, Vec2, Vec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let viewport_size: Vec2 = Vec2::new(800.0, 600.0);
+    ///
+    /// // A camera looking at the tile (4, 2, 1), zoomed out to half size.
+    /// let target: Vec3 = proj.world_to_transform(IVec3::new(4, 2, 1)).translation;
+    /// let camera: GlobalTransform = GlobalTransform::from(Transform::from_xyz(target.x, target.y, 100.0).with_scale(Vec3::splat(2.0)));
+    ///
+    /// let occupied = |pos: IVec3| pos.z <= 1;
+    /// let center: Vec2 = viewport_size / 2.0;
+    ///
+    /// assert_eq!(proj.cursor_to_world(&camera, center, viewport_size, 0..4, occupied), Some(IVec3::new(4, 2, 1)));
+    /// // One tile to the right on screen is 14 pixels at half size.
+    /// assert_eq!(proj.cursor_to_world(&camera, center + Vec2::new(14.0, 0.0), viewport_size, 0..4, occupied), Some(IVec3::new(5, 1, 1)));
+    /// assert_eq!(proj.cursor_to_world(&camera, center, viewport_size, 2..4, occupied), None);
+    /// # }
+    /// ```
+    pub fn cursor_to_world<F: Fn(IVec3) -> bool>(
+        &self,
+        camera_transform: &GlobalTransform,
+        cursor: Vec2,
+        viewport_size: Vec2,
+        z_range: Range<i32>,
+        occupied: F
+    ) -> Option<IVec3> {
+        let view_pos: Vec3 = Vec3::new(cursor.x - viewport_size.x * 0.5, viewport_size.y * 0.5 - cursor.y, 0.0);
+        let world_pos: Vec3 = camera_transform.transform_point(view_pos);
+        let drawn_pos: Vec2 = Vec2::new(world_pos.x, -self.y_axis.orient(world_pos.y));
+
+        self.pick_topmost(drawn_pos, z_range, occupied)
+    }
+}
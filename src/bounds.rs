@@ -0,0 +1,633 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::cmp::Ordering;
+use core::ops::Range;
+use glam::{ IVec2, IVec3 };
+use crate::{ Anchor, IsoProjection, Layout, Matrix2, Scalar, Vector2, Vector3, YAxis };
+
+impl<S: Scalar> IsoProjection<S> {
+    /// Returns the inclusive min and max grid x/y of every tile visible in a viewport.
+    ///
+    /// Isometric viewports cover a diamond of the grid, so the returned box is a
+    /// superset of the visible tiles: it holds every tile that a point inside the
+    /// viewport would pick with [`IsoProjection::tile_at_screen`] on the z = 0 level.
+    /// The box is in the coordinates of the projection's [`Layout`],
+    /// like the picked tiles. The box isn't clamped to any map, so clamp it to your
+    /// map bounds before iterating if the viewport can be larger than the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec2, IVec3, Vec2 };
+    /// use spriso::{ IsoProjection, Layout };
+    ///
+    /// for layout in [Layout::Diamond, Layout::Staggered] {
+    ///     let proj: IsoProjection = IsoProjection::new::<14, 14>().with_layout(layout);
+    ///
+    ///     let (min, max): (IVec2, IVec2) = proj.visible_tile_bounds(Vec2::new(-100.0, 0.0), Vec2::new(100.0, 60.0));
+    ///
+    ///     for y in 0..=60 {
+    ///         for x in -100..=100 {
+    ///             let tile: IVec3 = proj.tile_at_screen(Vec2::new(x as f32, y as f32), 0);
+    ///             assert!(tile.x >= min.x && tile.x <= max.x);
+    ///             assert!(tile.y >= min.y && tile.y <= max.y);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn visible_tile_bounds(&self, viewport_min: S::Vec2, viewport_max: S::Vec2) -> (IVec2, IVec2) {
+        let (min, max): (IVec2, IVec2) = self.visible_diamond_bounds(viewport_min, viewport_max);
+
+        // Staggered columns and rows grow with diamond x - y and x + y, so the box's corners hold their extremes.
+        let corners: [IVec2; 4] = [
+            min,
+            IVec2::new(max.x, min.y),
+            IVec2::new(min.x, max.y),
+            max,
+        ].map(|corner| self.layout.from_diamond(corner.extend(0)).truncate());
+
+        (
+            corners.into_iter().fold(corners[0], IVec2::min),
+            corners.into_iter().fold(corners[0], IVec2::max)
+        )
+    }
+
+    /// Returns the inclusive min and max diamond x/y of every tile visible in a viewport, see [`IsoProjection::visible_tile_bounds`].
+    pub(crate) fn visible_diamond_bounds(&self, viewport_min: S::Vec2, viewport_max: S::Vec2) -> (IVec2, IVec2) {
+        let corners: [S::Vec2; 4] = [
+            viewport_min,
+            S::Vec2::new(viewport_max.x(), viewport_min.y()),
+            S::Vec2::new(viewport_min.x(), viewport_max.y()),
+            viewport_max,
+        ].map(|corner| {
+            let world_pos: S::Vec3 = self.unproject_f(S::Vec3::new(corner.x(), corner.y(), S::ZERO), self.applied_origin());
+            S::Vec2::new(world_pos.x(), world_pos.y())
+        });
+
+        let min: S::Vec2 = corners.into_iter().fold(corners[0], S::Vec2::min);
+        let max: S::Vec2 = corners.into_iter().fold(corners[0], S::Vec2::max);
+
+        let half: S::Vec2 = S::Vec2::new(S::HALF, S::HALF);
+
+        ((min + half).floor().as_ivec2(), (max + half).floor().as_ivec2())
+    }
+
+    /// Returns how many grid columns and rows a viewport of `viewport_size` pixels spans.
+    ///
+    /// The size is mapped through the inverse matrix and zoom to the extent it
+    /// covers along diamond x and y, which is rounded up and grown by one for the
+    /// tiles cut by the viewport edges. Under [`Layout::Staggered`] that extent is
+    /// converted to the columns and rows it can reach. This is an upper bound on the
+    /// size of the box [`IsoProjection::visible_tile_bounds`] returns for any viewport
+    /// of that size, so render buffers can be sized without projecting anything.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec2, Vec2 };
+    /// use spriso::{ IsoProjection, Layout };
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// let count: IVec2 = proj.tiles_per_viewport(Vec2::new(280.0, 140.0));
+    /// assert_eq!(count, IVec2::new(21, 21));
+    ///
+    /// let (min, max): (IVec2, IVec2) = proj.visible_tile_bounds(Vec2::new(-13.0, 5.0), Vec2::new(267.0, 145.0));
+    /// assert!((max - min + IVec2::ONE).cmple(count).all());
+    ///
+    /// let staggered: IsoProjection = proj.with_layout(Layout::Staggered);
+    /// let count: IVec2 = staggered.tiles_per_viewport(Vec2::new(280.0, 140.0));
+    /// let (min, max): (IVec2, IVec2) = staggered.visible_tile_bounds(Vec2::new(-13.0, 5.0), Vec2::new(267.0, 145.0));
+    /// assert!((max - min + IVec2::ONE).cmple(count).all());
+    /// ```
+    pub fn tiles_per_viewport(&self, viewport_size: S::Vec2) -> IVec2 {
+        let x_col: S::Vec2 = self.inv_iso_matrix_2d.x_axis();
+        let y_col: S::Vec2 = self.inv_iso_matrix_2d.y_axis();
+        let (width, height): (S, S) = (viewport_size.x().abs(), viewport_size.y().abs());
+
+        let extent: S::Vec2 = S::Vec2::new(
+            x_col.x().abs() * width + y_col.x().abs() * height,
+            x_col.y().abs() * width + y_col.y().abs() * height
+        ) * self.inv_zoom;
+
+        let count: IVec2 = extent.ceil().as_ivec2() + IVec2::ONE;
+
+        match self.layout {
+            Layout::Diamond => count,
+            // Rows are diamond x + y and columns half of x - y, so both see the sum of the extents.
+            Layout::Staggered => IVec2::new((count.x + count.y - 1) / 2 + 1, count.x + count.y - 1),
+        }
+    }
+
+    /// Returns every tile on the `z_range` levels visible in a viewport, sorted back to front.
+    ///
+    /// Each level's tiles are the diamond grid box behind [`IsoProjection::visible_tile_bounds`]
+    /// once the viewport is moved down by that level's elevation, so like it this
+    /// is a superset of the visible tiles and isn't clamped to any map.
+    /// The tiles are then stable sorted by ascending [`IsoProjection::depth_key`].
+    /// The tiles are collected and sorted in a `Vec` allocated on every call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// let tiles: Vec<IVec3> = proj.visible_tiles_sorted(Vec2::new(-50.0, -30.0), Vec2::new(50.0, 30.0), 0..3).collect();
+    ///
+    /// assert!(tiles.windows(2).all(|pair| proj.depth_key(pair[0]) <= proj.depth_key(pair[1])));
+    /// for z in 0..3 {
+    ///     assert!(tiles.contains(&proj.tile_at_screen(Vec2::new(-50.0, -30.0), z)));
+    ///     assert!(tiles.contains(&proj.tile_at_screen(Vec2::new(50.0, 30.0), z)));
+    /// }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn visible_tiles_sorted(&self, viewport_min: S::Vec2, viewport_max: S::Vec2, z_range: Range<i32>) -> impl Iterator<Item = IVec3> {
+        let mut tiles: Vec<(S, IVec3)> = Vec::new();
+
+        for z in z_range {
+            let elevation: S = S::from_i32(z) * self.z_scale * self.zoom;
+            let shift: S::Vec2 = S::Vec2::new(S::ZERO, self.y_axis.orient(elevation));
+            let (min, max): (IVec2, IVec2) = self.visible_diamond_bounds(viewport_min + shift, viewport_max + shift);
+
+            for y in min.y..=max.y {
+                for x in min.x..=max.x {
+                    let world_pos: IVec3 = self.layout.from_diamond(IVec3::new(x, y, z));
+                    tiles.push((self.depth_key(world_pos), world_pos));
+                }
+            }
+        }
+
+        tiles.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+        tiles.into_iter().map(|(_, world_pos)| world_pos)
+    }
+
+    /// Returns the min and max screen corners of the diamond a tile is drawn as.
+    ///
+    /// The box spans the tile's full diamond, from its top to its bottom vertex and
+    /// from its left to its right vertex, and is moved up by the tile's elevation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// assert_eq!(proj.tile_screen_aabb(IVec3::ZERO), (Vec2::new(-14.0, -7.0), Vec2::new(14.0, 7.0)));
+    /// assert_eq!(proj.tile_screen_aabb(IVec3::new(0, 0, 1)), (Vec2::new(-14.0, -21.0), Vec2::new(14.0, -7.0)));
+    /// ```
+    pub fn tile_screen_aabb(&self, world_pos: IVec3) -> (S::Vec2, S::Vec2) {
+        let vertices: [S::Vec2; 4] = self.tile_corners(world_pos);
+
+        let min: S::Vec2 = vertices.into_iter().fold(vertices[0], S::Vec2::min);
+        let max: S::Vec2 = vertices.into_iter().fold(vertices[0], S::Vec2::max);
+
+        (min, max)
+    }
+
+    /// Returns whether the drawn diamond of a tile overlaps a viewport.
+    ///
+    /// The tile's [`IsoProjection::tile_screen_aabb`] is tested against the
+    /// viewport rect, edges included, so a tile partially on screen is never
+    /// culled. Tiles whose box only touches the viewport corner outside their
+    /// diamond may be kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let (viewport_min, viewport_max): (Vec2, Vec2) = (Vec2::new(0.0, 0.0), Vec2::new(100.0, 60.0));
+    ///
+    /// let mut tiles: Vec<IVec3> = vec![IVec3::new(0, 0, 0), IVec3::new(-5, -5, 0), IVec3::new(3, 3, 0), IVec3::new(3, 3, 10)];
+    /// tiles.retain(|tile| proj.is_tile_visible(*tile, viewport_min, viewport_max));
+    ///
+    /// assert_eq!(tiles, [IVec3::new(0, 0, 0), IVec3::new(3, 3, 0)]);
+    /// ```
+    pub fn is_tile_visible(&self, world_pos: IVec3, viewport_min: S::Vec2, viewport_max: S::Vec2) -> bool {
+        let (tile_min, tile_max): (S::Vec2, S::Vec2) = self.tile_screen_aabb(world_pos);
+
+        tile_min.x() <= viewport_max.x()
+            && tile_min.y() <= viewport_max.y()
+            && tile_max.x() >= viewport_min.x()
+            && tile_max.y() >= viewport_min.y()
+    }
+
+    /// Returns the screen vertices of the diamond a tile is drawn as.
+    ///
+    /// The vertices are ordered top, right, bottom, left, which winds clockwise on a
+    /// y-down screen, and are moved up by the tile's elevation like
+    /// [`IsoProjection::tile_screen_aabb`]. The order is the same in every
+    /// [`Orientation`](crate::Orientation), so it can be fed straight into a line strip.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// assert_eq!(proj.tile_corners(IVec3::new(0, 0, 1)), [
+    ///     Vec2::new(0.0, -21.0),
+    ///     Vec2::new(14.0, -14.0),
+    ///     Vec2::new(0.0, -7.0),
+    ///     Vec2::new(-14.0, -14.0),
+    /// ]);
+    /// ```
+    pub fn tile_corners(&self, world_pos: IVec3) -> [S::Vec2; 4] {
+        let center: S::Vec2 = self.drawn_position(world_pos);
+        let (down, right): (S::Vec2, S::Vec2) = self.tile_half_diagonals();
+
+        [center - down, center + right, center + down, center - right]
+    }
+
+    /// Returns the screen start and end points of every grid line bordering the tiles in an inclusive box, for a debug overlay.
+    ///
+    /// `min` and `max` are diamond grid x/y on the z = 0 level, which are the world
+    /// x/y of [`Layout::Diamond`], and may be given in either
+    /// order per component. Lines along grid y are yielded first, one per column
+    /// border, then lines along grid x, one per row border. Their endpoints are
+    /// taken from the [`IsoProjection::tile_corners`] of the tiles on the box's
+    /// edges, so the lines trace the tile edges exactly, bit for bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec2, IVec3, Vec2 };
+    /// use spriso::{ IsoProjection, Orientation };
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// let lines: Vec<(Vec2, Vec2)> = proj.grid_lines(IVec2::new(0, 0), IVec2::new(0, 0)).collect();
+    /// let [top, right, bottom, left]: [Vec2; 4] = proj.tile_corners(IVec3::ZERO);
+    ///
+    /// assert_eq!(lines, [(top, left), (right, bottom), (top, right), (left, bottom)]);
+    /// assert_eq!(proj.grid_lines(IVec2::new(3, 2), IVec2::new(-1, 0)).count(), 6 + 4);
+    ///
+    /// // Endpoints match the tile corners exactly, also at a fractional size and zoom.
+    /// for orientation in [Orientation::Deg0, Orientation::Deg90, Orientation::Deg180, Orientation::Deg270] {
+    ///     let mut proj: IsoProjection = IsoProjection::from_dims(13.7, 9.3)
+    ///         .with_origin(Vec2::new(0.1, 0.3))
+    ///         .with_orientation(orientation);
+    ///     proj.set_zoom(1.37);
+    ///
+    ///     let corners: Vec<Vec2> = (-5..=5)
+    ///         .flat_map(|y| (-5..=5).map(move |x| IVec3::new(x, y, 0)))
+    ///         .flat_map(|pos| proj.tile_corners(pos))
+    ///         .collect();
+    ///
+    ///     for (start, end) in proj.grid_lines(IVec2::splat(-5), IVec2::splat(5)) {
+    ///         assert!(corners.contains(&start) && corners.contains(&end));
+    ///     }
+    /// }
+    /// ```
+    pub fn grid_lines(&self, min: IVec2, max: IVec2) -> impl Iterator<Item = (S::Vec2, S::Vec2)> {
+        let (min, max): (IVec2, IVec2) = (min.min(max), min.max(max));
+
+        // Finds which of the `tile_corners` is the corner towards each diagonal of the
+        // diamond grid, which depends on the orientation, y axis and mirroring.
+        let (down, right): (S::Vec2, S::Vec2) = self.tile_half_diagonals();
+        let offsets: [S::Vec2; 4] = [-down, right, down, -right];
+        let vertex_index = |dx: S, dy: S| -> usize {
+            let offset: S::Vec2 = self.iso_matrix_2d * S::Vec2::new(dx, dy) * self.zoom;
+            let mut best: usize = 0;
+
+            for index in 1..4 {
+                if (offsets[index] - offset).length() < (offsets[best] - offset).length() {
+                    best = index;
+                }
+            }
+
+            best
+        };
+        let indices: [usize; 4] = [
+            vertex_index(-S::HALF, -S::HALF),
+            vertex_index(S::HALF, -S::HALF),
+            vertex_index(-S::HALF, S::HALF),
+            vertex_index(S::HALF, S::HALF),
+        ];
+
+        // Returns the corner of a diamond tile towards +x if `high_x` and +y if `high_y`.
+        let corner = move |x: i32, y: i32, high_x: bool, high_y: bool| {
+            let index: usize = indices[usize::from(high_x) + 2 * usize::from(high_y)];
+
+            self.tile_corners(self.layout.from_diamond(IVec3::new(x, y, 0)))[index]
+        };
+
+        let columns = (min.x..=max.x + 1).map(move |x| {
+            let (tile_x, high_x): (i32, bool) = if x > max.x { (max.x, true) } else { (x, false) };
+            (corner(tile_x, min.y, high_x, false), corner(tile_x, max.y, high_x, true))
+        });
+        let rows = (min.y..=max.y + 1).map(move |y| {
+            let (tile_y, high_y): (i32, bool) = if y > max.y { (max.y, true) } else { (y, false) };
+            (corner(min.x, tile_y, false, high_y), corner(max.x, tile_y, true, high_y))
+        });
+
+        columns.chain(rows)
+    }
+
+    /// Returns the screen vertices of the diamond a tile is drawn as, wound counter-clockwise.
+    ///
+    /// The vertices start at the top vertex and have a positive signed area in
+    /// screen coordinates, the winding polygon fill and triangulation libraries
+    /// usually expect. On a [`YAxis::Down`] screen this is the order of
+    /// [`IsoProjection::tile_corners`], which looks clockwise since y points down,
+    /// and on a [`YAxis::Up`] screen the left and right vertices are swapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2 };
+    /// use spriso::{ IsoProjection, YAxis };
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// let signed_area = |v: [Vec2; 4]| (0..4).map(|i| v[i].perp_dot(v[(i + 1) % 4])).sum::<f32>() / 2.0;
+    ///
+    /// assert_eq!(proj.tile_diamond(IVec3::ZERO), proj.tile_corners(IVec3::ZERO));
+    /// assert_eq!(signed_area(proj.tile_diamond(IVec3::ZERO)), 196.0);
+    /// assert_eq!(signed_area(proj.with_y_axis(YAxis::Up).tile_diamond(IVec3::new(2, -1, 3))), 196.0);
+    /// ```
+    pub fn tile_diamond(&self, world_pos: IVec3) -> [S::Vec2; 4] {
+        let [top, right, bottom, left]: [S::Vec2; 4] = self.tile_corners(world_pos);
+
+        match self.y_axis {
+            YAxis::Down => [top, right, bottom, left],
+            YAxis::Up => [top, left, bottom, right],
+        }
+    }
+
+    /// Returns whether a screen point lies inside the drawn diamond of a tile.
+    ///
+    /// The tile's z level is taken from `world_pos`. This agrees exactly with
+    /// [`IsoProjection::tile_at_screen`], so points on an edge or vertex shared with
+    /// other tiles are inside only the one tile `tile_at_screen` returns for them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let tile: IVec3 = IVec3::ZERO;
+    ///
+    /// assert!(proj.point_in_diamond(Vec2::new(0.0, 0.0), tile));
+    /// assert!(!proj.point_in_diamond(Vec2::new(14.0, 7.0), tile));
+    ///
+    /// // The edges and vertices towards the tiles behind belong to this tile.
+    /// assert!(proj.point_in_diamond(Vec2::new(0.0, -7.0), tile));
+    /// assert!(proj.point_in_diamond(Vec2::new(-7.0, -3.5), tile));
+    ///
+    /// // The edges and vertices towards the tiles in front belong to those tiles.
+    /// assert!(!proj.point_in_diamond(Vec2::new(7.0, 3.5), tile));
+    /// assert!(proj.point_in_diamond(Vec2::new(7.0, 3.5), IVec3::new(1, 0, 0)));
+    /// assert!(!proj.point_in_diamond(Vec2::new(0.0, 7.0), tile));
+    /// assert!(proj.point_in_diamond(Vec2::new(0.0, 7.0), IVec3::new(1, 1, 0)));
+    ///
+    /// // Elevated tiles are tested where they're drawn.
+    /// assert!(proj.point_in_diamond(Vec2::new(0.0, -14.0), IVec3::new(0, 0, 1)));
+    /// ```
+    pub fn point_in_diamond(&self, point: S::Vec2, world_pos: IVec3) -> bool {
+        self.tile_at_screen(point, world_pos.z) == world_pos
+    }
+
+    /// Returns the min and max screen corners enclosing every tile in an inclusive box of grid positions.
+    ///
+    /// The box covers each tile's full diamond and elevation like
+    /// [`IsoProjection::tile_screen_aabb`], so tiles on the box edges aren't clipped.
+    /// `min` and `max` may be given in either order per component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2 };
+    /// use spriso::{ IsoProjection, Layout };
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let (min, max): (Vec2, Vec2) = proj.region_screen_aabb(IVec3::new(0, 0, 0), IVec3::new(3, 2, 1));
+    ///
+    /// assert_eq!((min, max), (Vec2::new(-42.0, -21.0), Vec2::new(56.0, 42.0)));
+    ///
+    /// let staggered: IsoProjection = proj.with_layout(Layout::Staggered);
+    /// let (min, max): (Vec2, Vec2) = staggered.region_screen_aabb(IVec3::new(0, 0, 0), IVec3::new(3, 4, 0));
+    ///
+    /// for y in 0..=4 {
+    ///     for x in 0..=3 {
+    ///         let (tile_min, tile_max): (Vec2, Vec2) = staggered.tile_screen_aabb(IVec3::new(x, y, 0));
+    ///         assert!(tile_min.cmpge(min).all() && tile_max.cmple(max).all());
+    ///     }
+    /// }
+    /// ```
+    pub fn region_screen_aabb(&self, min: IVec3, max: IVec3) -> (S::Vec2, S::Vec2) {
+        let (min, max): (IVec3, IVec3) = (min.min(max), min.max(max));
+
+        // Projection is linear in diamond coordinates, so the box corners are the
+        // extremes. Staggered rows next to the edge rows are shifted by half a tile,
+        // so they're checked too.
+        let rows: [i32; 4] = [min.y, (min.y + 1).min(max.y), (max.y - 1).max(min.y), max.y];
+        let (mut region_min, mut region_max): (S::Vec2, S::Vec2) = self.tile_screen_aabb(min);
+
+        for z in [min.z, max.z] {
+            for y in rows {
+                for x in [min.x, max.x] {
+                    let (tile_min, tile_max): (S::Vec2, S::Vec2) = self.tile_screen_aabb(IVec3::new(x, y, z));
+                    region_min = region_min.min(tile_min);
+                    region_max = region_max.max(tile_max);
+                }
+            }
+        }
+
+        (region_min, region_max)
+    }
+
+    /// Returns the min and max screen corners of an object covering `size` tiles from `origin`.
+    ///
+    /// The object covers the tiles from `origin` to `origin + size - 1` on the
+    /// `origin.z` level, so this is [`IsoProjection::region_screen_aabb`] over that box.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a `size` component isn't positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec2, IVec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// assert_eq!(
+    ///     proj.footprint_screen_aabb(IVec3::new(5, 2, 1), IVec2::new(3, 2)),
+    ///     proj.region_screen_aabb(IVec3::new(5, 2, 1), IVec3::new(7, 3, 1))
+    /// );
+    /// ```
+    pub fn footprint_screen_aabb(&self, origin: IVec3, size: IVec2) -> (S::Vec2, S::Vec2) {
+        assert!(size.cmpgt(IVec2::ZERO).all(), "footprint size must be positive, got {size}");
+
+        self.region_screen_aabb(origin, origin + (size - IVec2::ONE).extend(0))
+    }
+
+    /// Returns the min and max screen corners of a whole map of `map_size` tiles.
+    ///
+    /// The map holds the tiles from zero up to but excluding `map_size` in each
+    /// axis, so the top level is `map_size.z - 1` and its elevation pushes the top of
+    /// the bounds upwards. This is [`IsoProjection::region_screen_aabb`] over that box.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a `map_size` component isn't positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// assert_eq!(proj.map_screen_bounds(IVec3::new(4, 3, 2)), proj.region_screen_aabb(IVec3::ZERO, IVec3::new(3, 2, 1)));
+    /// assert_eq!(proj.map_screen_bounds(IVec3::ONE), proj.tile_screen_aabb(IVec3::ZERO));
+    /// ```
+    pub fn map_screen_bounds(&self, map_size: IVec3) -> (S::Vec2, S::Vec2) {
+        assert!(map_size.cmpgt(IVec3::ZERO).all(), "map size must be positive, got {map_size}");
+
+        self.region_screen_aabb(IVec3::ZERO, map_size - IVec3::ONE)
+    }
+
+    /// Returns the top-left corner and size of the screen rect to draw a tile's sprite in.
+    ///
+    /// The sprite is centered horizontally on the tile, and vertically its point
+    /// matching the projection's [`Anchor`] sits on that point of the drawn diamond:
+    /// its top edge on the top vertex, its center on the center or its bottom edge on
+    /// the bottom vertex. The tile's elevation is included. With
+    /// [`YAxis::Up`] the top-left corner has the greatest y of the rect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2 };
+    /// use spriso::{ Anchor, IsoProjection };
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>().with_anchor(Anchor::Bottom);
+    ///
+    /// // A 28x28 block sprite sits on the diamond's bottom vertex at (0, 7).
+    /// assert_eq!(proj.tile_render_rect(IVec3::ZERO, Vec2::new(28.0, 28.0)), (Vec2::new(-14.0, -21.0), Vec2::new(28.0, 28.0)));
+    /// assert_eq!(proj.tile_render_rect(IVec3::new(0, 0, 1), Vec2::new(28.0, 40.0)), (Vec2::new(-14.0, -47.0), Vec2::new(28.0, 40.0)));
+    /// ```
+    pub fn tile_render_rect(&self, world_pos: IVec3, sprite_size: S::Vec2) -> (S::Vec2, S::Vec2) {
+        let center: S::Vec2 = self.drawn_position(world_pos);
+        let (down, _): (S::Vec2, S::Vec2) = self.tile_half_diagonals();
+
+        let (anchor_pos, height_above): (S::Vec2, S) = match self.anchor {
+            Anchor::Top => (center - down, S::ZERO),
+            Anchor::Center => (center, sprite_size.y() * S::HALF),
+            Anchor::Bottom => (center + down, sprite_size.y()),
+        };
+        let top_left: S::Vec2 = S::Vec2::new(
+            anchor_pos.x() - sprite_size.x() * S::HALF,
+            anchor_pos.y() - self.y_axis.orient(height_above)
+        );
+
+        (top_left, sprite_size)
+    }
+
+    /// Returns every other tile on the `z_range` levels whose sprite overlaps the sprite of `world_pos`.
+    ///
+    /// Two sprites overlap when their [`IsoProjection::tile_render_rect`]s of
+    /// `sprite_size` share more than an edge, so these are the tiles to redraw when
+    /// `world_pos` changes. Elevation is included, so tall sprites on low levels
+    /// and overhanging sprites on high levels are found too. Tiles are yielded level
+    /// by level and row by row, without `world_pos` itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let sprite_size: Vec2 = Vec2::new(28.0, 28.0);
+    ///
+    /// let changed: IVec3 = IVec3::new(2, 2, 0);
+    /// let dirty: Vec<IVec3> = proj.overlapping_tiles(changed, 0..3, sprite_size).collect();
+    ///
+    /// assert!(!dirty.contains(&changed));
+    /// assert!(dirty.contains(&IVec3::new(3, 2, 0)));
+    /// // The block one level up on the tile in front is drawn right over the changed tile.
+    /// assert!(dirty.contains(&IVec3::new(3, 3, 1)));
+    /// assert!(dirty.contains(&IVec3::new(2, 2, 1)));
+    /// assert!(!dirty.contains(&IVec3::new(4, 2, 0)));
+    ///
+    /// let (min, size): (Vec2, Vec2) = proj.tile_render_rect(changed, sprite_size);
+    /// for pos in dirty {
+    ///     let (other_min, _): (Vec2, Vec2) = proj.tile_render_rect(pos, sprite_size);
+    ///     assert!((other_min - min).abs().cmplt(size).all());
+    /// }
+    /// ```
+    pub fn overlapping_tiles(&self, world_pos: IVec3, z_range: Range<i32>, sprite_size: S::Vec2) -> impl Iterator<Item = IVec3> {
+        let center: S::Vec2 = self.drawn_position(world_pos);
+
+        z_range.flat_map(move |z| {
+            let elevation: S = S::from_i32(z) * self.z_scale * self.zoom;
+            let ground_center: S::Vec2 = S::Vec2::new(center.x(), center.y() + self.y_axis.orient(elevation));
+            let (min, max): (IVec2, IVec2) = self.visible_diamond_bounds(ground_center - sprite_size, ground_center + sprite_size);
+
+            (min.y..=max.y)
+                .flat_map(move |y| (min.x..=max.x).map(move |x| IVec3::new(x, y, z)))
+                .map(move |diamond_pos| self.layout.from_diamond(diamond_pos))
+        })
+        .filter(move |other| {
+            // Every render rect sits at the same offset from its tile's drawn center.
+            let offset: S::Vec2 = self.drawn_position(*other) - center;
+
+            *other != world_pos && offset.x().abs() < sprite_size.x() && offset.y().abs() < sprite_size.y()
+        })
+    }
+
+    /// Returns every tile on the `world_z` level whose drawn center lies inside a screen circle.
+    ///
+    /// Tiles are yielded once each, row by row. A tile exactly on the circle is
+    /// inside, so a radius of zero yields the tile centered on `center`, if any, and
+    /// a negative radius yields nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec2, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// let tiles: Vec<IVec2> = proj.tiles_in_screen_circle(Vec2::ZERO, 16.0, 0).collect();
+    /// assert_eq!(tiles, [IVec2::new(-1, -1), IVec2::new(0, -1), IVec2::new(-1, 0), IVec2::new(0, 0), IVec2::new(1, 0), IVec2::new(0, 1), IVec2::new(1, 1)]);
+    ///
+    /// assert_eq!(proj.tiles_in_screen_circle(Vec2::new(0.0, -14.0), 0.0, 1).collect::<Vec<IVec2>>(), [IVec2::ZERO]);
+    /// assert_eq!(proj.tiles_in_screen_circle(Vec2::new(3.0, 0.0), 0.0, 0).count(), 0);
+    /// ```
+    pub fn tiles_in_screen_circle(&self, center: S::Vec2, radius: S, world_z: i32) -> impl Iterator<Item = IVec2> {
+        let elevation: S = S::from_i32(world_z) * self.z_scale * self.zoom;
+        let ground_center: S::Vec2 = S::Vec2::new(center.x(), center.y() + self.y_axis.orient(elevation));
+        let extent: S::Vec2 = S::Vec2::new(radius, radius);
+        let (min, max): (IVec2, IVec2) = self.visible_diamond_bounds(ground_center - extent, ground_center + extent);
+
+        (min.y..=max.y)
+            .flat_map(move |y| (min.x..=max.x).map(move |x| IVec3::new(x, y, world_z)))
+            .map(move |diamond_pos| self.layout.from_diamond(diamond_pos))
+            .filter(move |world_pos| {
+                let offset: S::Vec2 = self.drawn_position(*world_pos) - center;
+
+                radius >= S::ZERO && offset.x() * offset.x() + offset.y() * offset.y() <= radius * radius
+            })
+            .map(IVec3::truncate)
+    }
+}
@@ -0,0 +1,215 @@
+use crate::{ Anchor, IsoProjection, Layout, Matrix2, Orientation, ProjectionError, Scalar, Vector2, YAxis };
+
+/// Configures every option of an [`IsoProjection`] and validates it once.
+///
+/// Only the half-tile size or the basis is required. The z scale defaults to the half-tile
+/// height, the origin and layer offset to zero, the parallax and zoom to one and the layout,
+/// orientation, y axis and anchor to their defaults with no mirroring, matching [`IsoProjection::from_dims`].
+///
+/// # Examples
+///
+/// ```
+/// use glam::{ IVec3, Vec2, Vec3 };
+/// use spriso::{ IsoProjection, IsoProjectionBuilder, Orientation };
+///
+/// let proj: IsoProjection = IsoProjectionBuilder::new()
+///     .half_tile(14.0, 14.0)
+///     .origin(Vec2::new(320.0, 240.0))
+///     .zoom(2.0)
+///     .orientation(Orientation::Deg90)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(proj.world_to_screen(IVec3::ZERO), Vec3::new(320.0, 240.0, 0.0));
+/// assert_eq!(proj.zoom(), 2.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IsoProjectionBuilder<S: Scalar = f32> {
+    /// Half the width and height of an isometric tile in screen pixels.
+    half_tile: Option<(S, S)>,
+    
+    /// Screen basis vectors of the world x and y axes, overriding the half-tile size.
+    basis: Option<(S::Vec2, S::Vec2)>,
+    
+    /// Scalar for Z-axis scaling, or `None` to use the half-tile height.
+    z_scale: Option<S>,
+    
+    /// Screen position that the world origin maps to.
+    origin: S::Vec2,
+    
+    /// Scale applied to the origin offset only.
+    parallax: S,
+    
+    /// Screen y offset of the whole layer in pixels.
+    layer_offset: S,
+    
+    /// Uniform scale applied to screen coordinates.
+    zoom: S,
+    
+    /// How integer grid x/y are laid out on screen.
+    layout: Layout,
+    
+    /// Rotation of the world x/y before projection.
+    orientation: Orientation,
+    
+    /// Which way screen +y points.
+    y_axis: YAxis,
+    
+    /// Whether screen x is negated to flip the map horizontally.
+    mirror_x: bool,
+    
+    /// The point of a tile's diamond that screen positions refer to.
+    anchor: Anchor,
+}
+
+impl<S: Scalar> IsoProjectionBuilder<S> {
+    /// Creates a builder with every option at its default and no tile size.
+    pub fn new() -> Self {
+        Self {
+            half_tile: None,
+            basis: None,
+            z_scale: None,
+            origin: S::Vec2::new(S::ZERO, S::ZERO),
+            parallax: S::ONE,
+            layer_offset: S::ZERO,
+            zoom: S::ONE,
+            layout: Layout::Diamond,
+            orientation: Orientation::Deg0,
+            y_axis: YAxis::Down,
+            mirror_x: false,
+            anchor: Anchor::Center,
+        }
+    }
+
+    /// Sets half the width and height of an isometric tile in screen pixels.
+    pub fn half_tile(mut self, half_tw: S, half_th: S) -> Self {
+        self.half_tile = Some((half_tw, half_th));
+        self
+    }
+
+    /// Sets the screen basis vectors of the world x and y axes, see [`IsoProjection::from_basis`].
+    ///
+    /// Takes precedence over [`IsoProjectionBuilder::half_tile`].
+    pub fn basis(mut self, x_axis: S::Vec2, y_axis: S::Vec2) -> Self {
+        self.basis = Some((x_axis, y_axis));
+        self
+    }
+
+    /// Sets the screen position that the world origin maps to.
+    pub fn origin(mut self, origin: S::Vec2) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Sets the factor the origin offset is scaled by, see [`IsoProjection::set_parallax`].
+    pub fn parallax(mut self, parallax: S) -> Self {
+        self.parallax = parallax;
+        self
+    }
+
+    /// Sets the screen y offset of the whole layer, see [`IsoProjection::set_layer_offset`].
+    pub fn layer_offset(mut self, offset: S) -> Self {
+        self.layer_offset = offset;
+        self
+    }
+
+    /// Sets the uniform scale applied to screen coordinates.
+    pub fn zoom(mut self, zoom: S) -> Self {
+        self.zoom = zoom;
+        self
+    }
+
+    /// Sets how many screen pixels one world z level is drawn as, before zoom.
+    pub fn z_scale(mut self, z_scale: S) -> Self {
+        self.z_scale = Some(z_scale);
+        self
+    }
+
+    /// Sets how integer grid x/y are laid out on screen.
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets the rotation of the world x/y before projection.
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets which way screen +y points.
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.y_axis = y_axis;
+        self
+    }
+
+    /// Sets whether screen x is negated to flip the map horizontally.
+    pub fn mirror_x(mut self, mirror_x: bool) -> Self {
+        self.mirror_x = mirror_x;
+        self
+    }
+
+    /// Sets the point of a tile's diamond that screen positions refer to.
+    pub fn anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Builds the projection, validating the final configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError::MissingTileSize`] if neither [`IsoProjectionBuilder::half_tile`]
+    /// nor [`IsoProjectionBuilder::basis`] was called, and [`ProjectionError::DegenerateMatrix`] if the matrix,
+    /// z scale or zoom is zero or non-finite or the parallax or layer offset is non-finite,
+    /// and [`ProjectionError::DegenerateBasis`] if the half-tile size or basis gives a non-positive tile size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spriso::{ IsoProjection, IsoProjectionBuilder, ProjectionError };
+    ///
+    /// assert_eq!(IsoProjectionBuilder::<f32>::new().build(), Err(ProjectionError::MissingTileSize));
+    /// assert_eq!(IsoProjection::<f32>::builder().half_tile(14.0, 14.0).zoom(0.0).build(), Err(ProjectionError::DegenerateMatrix));
+    /// assert_eq!(IsoProjection::builder().half_tile(14.0, 14.0).build(), Ok(IsoProjection::new::<14, 14>()));
+    /// ```
+    pub fn build(self) -> Result<IsoProjection<S>, ProjectionError> {
+        let basis: S::Mat2 = match (self.basis, self.half_tile) {
+            (Some((x_axis, y_axis)), _) => S::Mat2::from_cols(x_axis, y_axis),
+            (None, Some((half_tw, half_th))) => IsoProjection::tile_basis(half_tw, half_th),
+            (None, None) => return Err(ProjectionError::MissingTileSize),
+        };
+        let half_th: S = basis.x_axis().y() + basis.y_axis().y();
+
+        if self.zoom == S::ZERO || !self.zoom.is_finite() || !self.parallax.is_finite() || !self.layer_offset.is_finite() {
+            return Err(ProjectionError::DegenerateMatrix);
+        }
+
+        let mut proj: IsoProjection<S> = IsoProjection::from_parts(basis, self.z_scale.unwrap_or(half_th))
+            .with_origin(self.origin)
+            .with_layout(self.layout)
+            .with_orientation(self.orientation)
+            .with_y_axis(self.y_axis)
+            .with_mirror_x(self.mirror_x)
+            .with_anchor(self.anchor)
+            .validate()?;
+        proj.set_zoom(self.zoom);
+        proj.set_parallax(self.parallax);
+        proj.set_layer_offset(self.layer_offset);
+
+        Ok(proj)
+    }
+}
+
+impl<S: Scalar> Default for IsoProjectionBuilder<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Scalar> IsoProjection<S> {
+    /// Returns an [`IsoProjectionBuilder`] to configure a projection in one place.
+    pub fn builder() -> IsoProjectionBuilder<S> {
+        IsoProjectionBuilder::new()
+    }
+}
@@ -0,0 +1,30 @@
+use core::fmt;
+
+/// Errors returned when an [`IsoProjection`](crate::IsoProjection) can't be built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectionError {
+    /// The projection has a zero or non-finite determinant or z scale, so it can't be inverted.
+    DegenerateMatrix,
+
+    /// An [`IsoProjectionBuilder`](crate::IsoProjectionBuilder) was built without a tile size.
+    MissingTileSize,
+
+    /// A tile size required to be 2:1 doesn't have a width of exactly twice its height.
+    AspectRatio,
+
+    /// The tile has a negative width or height, e.g. from basis vectors given to [`IsoProjection::from_basis`](crate::IsoProjection::from_basis).
+    DegenerateBasis,
+}
+
+impl fmt::Display for ProjectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DegenerateMatrix => f.write_str("projection matrix is degenerate and can't be inverted"),
+            Self::MissingTileSize => f.write_str("projection builder has no half-tile size set"),
+            Self::AspectRatio => f.write_str("tile width isn't exactly twice its height"),
+            Self::DegenerateBasis => f.write_str("tile has no positive width or height"),
+        }
+    }
+}
+
+impl core::error::Error for ProjectionError {}
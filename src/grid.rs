@@ -0,0 +1,313 @@
+//! World space helpers for walking the isometric grid.
+//!
+//! Everything here works on grid positions only, so the results can be passed
+//! straight to [`IsoProjection::world_to_screen`](crate::IsoProjection::world_to_screen).
+
+use glam::{ IVec2, IVec3 };
+
+/// One of the eight directions from a tile to its neighbors on the same z level.
+///
+/// Directions are in world space: east is +x, south is +y, west is -x and north is -y.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// One step along -y.
+    North,
+
+    /// One step along +x and -y.
+    NorthEast,
+
+    /// One step along +x.
+    East,
+
+    /// One step along +x and +y.
+    SouthEast,
+
+    /// One step along +y.
+    South,
+
+    /// One step along -x and +y.
+    SouthWest,
+
+    /// One step along -x.
+    West,
+
+    /// One step along -x and -y.
+    NorthWest,
+}
+
+impl Direction {
+    /// The four cardinal directions, clockwise from north.
+    pub const CARDINAL: [Direction; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// All eight directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    /// Returns the grid offset of one step in this direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::IVec3;
+    /// use spriso::grid::Direction;
+    ///
+    /// assert_eq!(Direction::East.to_offset(), IVec3::X);
+    /// assert_eq!(Direction::NorthWest.to_offset(), IVec3::new(-1, -1, 0));
+    /// ```
+    pub fn to_offset(self) -> IVec3 {
+        match self {
+            Self::North => IVec3::new(0, -1, 0),
+            Self::NorthEast => IVec3::new(1, -1, 0),
+            Self::East => IVec3::new(1, 0, 0),
+            Self::SouthEast => IVec3::new(1, 1, 0),
+            Self::South => IVec3::new(0, 1, 0),
+            Self::SouthWest => IVec3::new(-1, 1, 0),
+            Self::West => IVec3::new(-1, 0, 0),
+            Self::NorthWest => IVec3::new(-1, -1, 0),
+        }
+    }
+}
+
+/// Returns the four cardinal neighbors of a tile, in [`Direction::CARDINAL`] order.
+///
+/// # Examples
+///
+/// ```
+/// use glam::IVec3;
+/// use spriso::grid;
+///
+/// assert_eq!(grid::neighbors(IVec3::new(5, 5, 2)), [
+///     IVec3::new(5, 4, 2),
+///     IVec3::new(6, 5, 2),
+///     IVec3::new(5, 6, 2),
+///     IVec3::new(4, 5, 2),
+/// ]);
+/// ```
+pub fn neighbors(world_pos: IVec3) -> [IVec3; 4] {
+    Direction::CARDINAL.map(|dir| world_pos + dir.to_offset())
+}
+
+/// Returns all eight neighbors of a tile including diagonals, in [`Direction::ALL`] order.
+///
+/// # Examples
+///
+/// ```
+/// use glam::IVec3;
+/// use spriso::grid;
+///
+/// let neighbors: [IVec3; 8] = grid::all_neighbors(IVec3::ZERO);
+///
+/// assert_eq!(neighbors[1], IVec3::new(1, -1, 0));
+/// assert!(neighbors.iter().all(|pos| pos.x.abs().max(pos.y.abs()) == 1 && pos.z == 0));
+/// ```
+pub fn all_neighbors(world_pos: IVec3) -> [IVec3; 8] {
+    Direction::ALL.map(|dir| world_pos + dir.to_offset())
+}
+
+/// Returns the number of cardinal steps between two grid x/y positions, `|dx| + |dy|`.
+///
+/// This is the distance when moving only between [`neighbors`].
+///
+/// # Examples
+///
+/// ```
+/// use glam::IVec2;
+/// use spriso::grid;
+///
+/// assert_eq!(grid::manhattan_distance(IVec2::new(1, 2), IVec2::new(4, -2)), 7);
+/// assert_eq!(grid::manhattan_distance(IVec2::ONE, IVec2::ONE), 0);
+/// ```
+pub fn manhattan_distance(a: IVec2, b: IVec2) -> i32 {
+    (a - b).abs().element_sum()
+}
+
+/// Returns the number of steps between two grid x/y positions counting diagonal steps as one, `max(|dx|, |dy|)`.
+///
+/// This is the Chebyshev distance, the distance when moving between
+/// [`all_neighbors`]. It matches [`ring`], and [`tiles_on_line`] yields one more
+/// tile than this distance between its endpoints.
+///
+/// # Examples
+///
+/// ```
+/// use glam::IVec2;
+/// use spriso::grid;
+///
+/// let (a, b): (IVec2, IVec2) = (IVec2::new(1, 2), IVec2::new(4, -2));
+///
+/// assert_eq!(grid::chebyshev_distance(a, b), 4);
+/// assert_eq!(grid::tiles_on_line(a, b).count(), 5);
+/// ```
+pub fn chebyshev_distance(a: IVec2, b: IVec2) -> i32 {
+    (a - b).abs().max_element()
+}
+
+/// Returns the tiles on a straight line between two grid x/y positions, using Bresenham's algorithm.
+///
+/// Both endpoints are included and the tiles are yielded in order from `start`
+/// to `end`. Consecutive tiles are 8-connected, so a line steps diagonally where
+/// needed and yields `max(|dx|, |dy|) + 1` tiles. Lines are traced in grid space,
+/// so the result doesn't depend on any projection.
+///
+/// # Examples
+///
+/// ```
+/// use glam::IVec2;
+/// use spriso::grid;
+///
+/// let line: Vec<IVec2> = grid::tiles_on_line(IVec2::new(0, 0), IVec2::new(4, 2)).collect();
+///
+/// assert_eq!(line, [IVec2::new(0, 0), IVec2::new(1, 1), IVec2::new(2, 1), IVec2::new(3, 2), IVec2::new(4, 2)]);
+/// assert_eq!(grid::tiles_on_line(IVec2::new(2, 5), IVec2::new(2, 1)).count(), 5);
+/// assert!(grid::tiles_on_line(IVec2::new(-3, 3), IVec2::new(3, -3)).all(|pos| pos.x == -pos.y));
+/// assert_eq!(grid::tiles_on_line(IVec2::ONE, IVec2::ONE).collect::<Vec<IVec2>>(), [IVec2::ONE]);
+/// ```
+pub fn tiles_on_line(start: IVec2, end: IVec2) -> impl Iterator<Item = IVec2> {
+    // Widened so lines spanning the whole i32 range can't overflow.
+    let delta_x: i64 = i64::from(end.x) - i64::from(start.x);
+    let delta_y: i64 = i64::from(end.y) - i64::from(start.y);
+    let step: IVec2 = IVec2::new(delta_x.signum() as i32, delta_y.signum() as i32);
+    let dx: i64 = delta_x.abs();
+    let dy: i64 = -delta_y.abs();
+
+    let mut err: i64 = dx + dy;
+    let mut next: Option<IVec2> = Some(start);
+
+    core::iter::from_fn(move || {
+        let pos: IVec2 = next?;
+
+        next = if pos == end {
+            None
+        } else {
+            let mut pos: IVec2 = pos;
+            let e2: i64 = 2 * err;
+
+            if e2 >= dy {
+                err += dy;
+                pos.x += step.x;
+            }
+            if e2 <= dx {
+                err += dx;
+                pos.y += step.y;
+            }
+
+            Some(pos)
+        };
+
+        Some(pos)
+    })
+}
+
+/// Returns the tiles at exactly `radius` steps from `center`, counting diagonal steps as one.
+///
+/// Distance is the Chebyshev distance `max(|dx|, |dy|)`, the number of moves a
+/// tile needs to reach another with [`all_neighbors`], so each ring is the border
+/// of a square of `2 * radius + 1` tiles and holds `8 * radius` of them. Tiles are
+/// yielded row by row. A radius of zero yields just `center` and a negative
+/// radius yields nothing.
+///
+/// # Examples
+///
+/// ```
+/// use glam::IVec2;
+/// use spriso::grid;
+///
+/// let ring: Vec<IVec2> = grid::ring(IVec2::new(5, 5), 1).collect();
+///
+/// assert_eq!(ring, [
+///     IVec2::new(4, 4), IVec2::new(5, 4), IVec2::new(6, 4),
+///     IVec2::new(4, 5), IVec2::new(6, 5),
+///     IVec2::new(4, 6), IVec2::new(5, 6), IVec2::new(6, 6),
+/// ]);
+/// assert_eq!(grid::ring(IVec2::ZERO, 3).count(), 24);
+/// assert_eq!(grid::ring(IVec2::ZERO, 0).collect::<Vec<IVec2>>(), [IVec2::ZERO]);
+/// assert_eq!(grid::ring(IVec2::ZERO, -1).count(), 0);
+/// ```
+pub fn ring(center: IVec2, radius: i32) -> impl Iterator<Item = IVec2> {
+    (-radius..=radius).flat_map(move |dy| {
+        // Edge rows are full, the rows between only have their two ends.
+        let step: usize = if dy.abs() == radius { 1 } else { 2 * radius as usize };
+
+        (-radius..=radius).step_by(step).map(move |dx| center + IVec2::new(dx, dy))
+    })
+}
+
+/// Returns the tiles up to `max_radius` steps from `center`, nearest first.
+///
+/// This is every [`ring`] from radius zero to `max_radius` in turn, so it yields
+/// `center`, then its eight neighbors and so on outwards, e.g. to load the
+/// nearest chunks first.
+///
+/// # Examples
+///
+/// ```
+/// use glam::IVec2;
+/// use spriso::grid;
+///
+/// let tiles: Vec<IVec2> = grid::spiral(IVec2::new(2, -1), 2).collect();
+///
+/// assert_eq!(tiles.len(), 25);
+/// assert_eq!(tiles[0], IVec2::new(2, -1));
+/// assert!(tiles.windows(2).all(|pair| {
+///     let distance = |pos: IVec2| (pos - IVec2::new(2, -1)).abs().max_element();
+///     distance(pair[0]) <= distance(pair[1])
+/// }));
+/// ```
+pub fn spiral(center: IVec2, max_radius: i32) -> impl Iterator<Item = IVec2> {
+    (0..=max_radius).flat_map(move |radius| ring(center, radius))
+}
+
+/// Splits a grid x/y position into the index of its chunk and its local position in that chunk.
+///
+/// Chunks are `chunk_size` tiles large with chunk `(0, 0)` starting at the grid
+/// origin. Negative positions use floor division, so `-1` is the last tile of
+/// chunk `-1` rather than part of chunk `0`, and the local position is always in
+/// `0..chunk_size`.
+///
+/// # Panics
+///
+/// Panics if a `chunk_size` component isn't positive.
+///
+/// # Examples
+///
+/// ```
+/// use glam::IVec2;
+/// use spriso::grid;
+///
+/// let chunk_size: IVec2 = IVec2::splat(16);
+///
+/// assert_eq!(grid::tile_to_chunk(IVec2::new(17, 3), chunk_size), (IVec2::new(1, 0), IVec2::new(1, 3)));
+/// assert_eq!(grid::tile_to_chunk(IVec2::new(-1, -16), chunk_size), (IVec2::new(-1, -1), IVec2::new(15, 0)));
+/// ```
+pub fn tile_to_chunk(world_pos: IVec2, chunk_size: IVec2) -> (IVec2, IVec2) {
+    assert!(chunk_size.cmpgt(IVec2::ZERO).all(), "chunk size must be positive, got {chunk_size}");
+
+    (world_pos.div_euclid(chunk_size), world_pos.rem_euclid(chunk_size))
+}
+
+/// Returns the grid x/y position of a local position in a chunk, the inverse of [`tile_to_chunk`].
+///
+/// # Examples
+///
+/// ```
+/// use glam::IVec2;
+/// use spriso::grid;
+///
+/// let chunk_size: IVec2 = IVec2::new(16, 8);
+///
+/// for pos in [IVec2::new(-33, 7), IVec2::new(0, -1), IVec2::new(100, 100)] {
+///     let (chunk, local): (IVec2, IVec2) = grid::tile_to_chunk(pos, chunk_size);
+///     assert_eq!(grid::chunk_to_tile(chunk, local, chunk_size), pos);
+/// }
+/// ```
+pub fn chunk_to_tile(chunk: IVec2, local: IVec2, chunk_size: IVec2) -> IVec2 {
+    chunk * chunk_size + local
+}
@@ -0,0 +1,93 @@
+use glam::{ I64Vec3, IVec3 };
+
+/// How integer grid x/y are laid out on screen.
+///
+/// The projection always works on a diamond grid internally. Other layouts are
+/// converted to and from diamond coordinates at the integer grid boundary, so the
+/// fractional `_f` conversions always treat positions as diamond coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Layout {
+    /// x runs down-right and y runs down-left, so the map forms a diamond.
+    #[default]
+    Diamond,
+
+    /// x is the column and y the row, with odd rows shifted right by half a tile,
+    /// so the map forms a rectangle.
+    Staggered,
+}
+
+impl Layout {
+    /// Converts a grid position in this layout to diamond coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::IVec3;
+    /// use spriso::Layout;
+    ///
+    /// assert_eq!(Layout::Staggered.to_diamond(IVec3::new(0, 1, 0)), IVec3::new(1, 0, 0));
+    /// assert_eq!(Layout::Staggered.to_diamond(IVec3::new(0, 2, 0)), IVec3::new(1, 1, 0));
+    /// assert_eq!(Layout::Diamond.to_diamond(IVec3::new(0, 1, 0)), IVec3::new(0, 1, 0));
+    /// ```
+    pub fn to_diamond(self, pos: IVec3) -> IVec3 {
+        match self {
+            Self::Diamond => pos,
+            Self::Staggered => {
+                let parity: i32 = pos.y & 1;
+
+                IVec3::new(pos.x + (pos.y + parity) / 2, (pos.y - parity) / 2 - pos.x, pos.z)
+            }
+        }
+    }
+
+    /// Converts a grid position in diamond coordinates to this layout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::IVec3;
+    /// use spriso::Layout;
+    ///
+    /// for y in -5..5 {
+    ///     for x in -5..5 {
+    ///         let pos: IVec3 = IVec3::new(x, y, 3);
+    ///         assert_eq!(Layout::Staggered.from_diamond(Layout::Staggered.to_diamond(pos)), pos);
+    ///     }
+    /// }
+    /// ```
+    pub fn from_diamond(self, pos: IVec3) -> IVec3 {
+        match self {
+            Self::Diamond => pos,
+            Self::Staggered => {
+                let row: i32 = pos.x + pos.y;
+
+                IVec3::new((pos.x - pos.y - (row & 1)) / 2, row, pos.z)
+            }
+        }
+    }
+
+    /// Converts a 64-bit grid position in this layout to diamond coordinates, see [`Layout::to_diamond`].
+    pub(crate) fn to_diamond_i64(self, pos: I64Vec3) -> I64Vec3 {
+        match self {
+            Self::Diamond => pos,
+            Self::Staggered => {
+                let parity: i64 = pos.y & 1;
+
+                I64Vec3::new(pos.x + (pos.y + parity) / 2, (pos.y - parity) / 2 - pos.x, pos.z)
+            }
+        }
+    }
+
+    /// Converts a 64-bit grid position in diamond coordinates to this layout, see [`Layout::from_diamond`].
+    pub(crate) fn to_layout_i64(self, pos: I64Vec3) -> I64Vec3 {
+        match self {
+            Self::Diamond => pos,
+            Self::Staggered => {
+                let row: i64 = pos.x + pos.y;
+
+                I64Vec3::new((pos.x - pos.y - (row & 1)) / 2, row, pos.z)
+            }
+        }
+    }
+}
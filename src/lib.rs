@@ -1,24 +1,237 @@
-use glam::{ Mat2, Vec2, Vec3, IVec3 };
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::ops::Range;
+use glam::{ I64Vec3, IVec2, IVec3, Mat2, Vec2 };
+
+pub mod grid;
+
+mod anchor;
+mod batch;
+mod bounds;
+mod builder;
+mod error;
+mod layout;
+mod orientation;
+mod position;
+mod rounding;
+mod scalar;
+mod y_axis;
+#[cfg(feature = "approx")]
+mod approx;
+#[cfg(feature = "bevy")]
+mod bevy;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "serde")]
+mod serde_repr;
+
+pub use anchor::Anchor;
+pub use builder::IsoProjectionBuilder;
+pub use error::ProjectionError;
+pub use layout::Layout;
+pub use orientation::Orientation;
+pub use position::{ ScreenPos, WorldPos };
+pub use rounding::RoundingMode;
+pub use scalar::{ Affine, Matrix2, Scalar, Vector2, Vector3 };
+pub use y_axis::YAxis;
 
 /// Represents an isometric projection to convert between 3D world grid positions
 /// and 2D screen coordinates.
-pub struct IsoProjection {
+///
+/// # Examples
+///
+/// ```
+/// use spriso::IsoProjection;
+///
+/// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+/// let copy: IsoProjection = proj;
+///
+/// assert_eq!(proj, copy);
+/// ```
+///
+/// The projection is generic over its [`Scalar`], defaulting to `f32`. Large worlds
+/// can compute in `f64` with `DVec2` and `DVec3` to keep precision far from the origin.
+///
+/// ```
+/// use glam::{ DVec3, IVec3 };
+/// use spriso::IsoProjection;
+///
+/// let proj: IsoProjection<f64> = IsoProjection::from_dims(14.0, 14.0);
+///
+/// let pos: IVec3 = IVec3::new(10_000_000, 20_000_000, 30);
+/// let screen_pos: DVec3 = proj.world_to_screen(pos);
+///
+/// assert_eq!(proj.screen_to_world(screen_pos), pos);
+/// ```
+///
+/// With the `serde` feature enabled the projection can be serialized. Only a format
+/// version and the source parameters are stored, the matrices are rebuilt on load, so
+/// older saves keep loading. Payloads from a newer version are rejected.
+///
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// use glam::Vec2;
+/// use spriso::{ IsoProjection, Orientation };
+///
+/// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+/// let json: String = serde_json::to_string(&proj).unwrap();
+///
+/// assert_eq!(json, r#"{"version":1,"half_tw":14.0,"half_th":14.0,"z_scale":14.0,"origin":[0.0,0.0],"parallax":1.0,"layer_offset":0.0,"zoom":1.0,"layout":"Diamond","orientation":"Deg0","y_axis":"Down","mirror_x":false,"anchor":"Center"}"#);
+/// assert_eq!(serde_json::from_str::<IsoProjection>(&json).unwrap(), proj);
+/// assert!(serde_json::from_str::<IsoProjection>(r#"{"half_tw":0.0,"half_th":14.0,"z_scale":14.0}"#).is_err());
+/// assert!(serde_json::from_str::<IsoProjection>(r#"{"version":2,"half_tw":14.0,"half_th":14.0,"z_scale":14.0}"#).is_err());
+///
+/// // A save from before the version tag, with only the fields it knew about.
+/// let legacy: IsoProjection = serde_json::from_str(r#"{"half_tw":14.0,"half_th":7.0,"z_scale":8.0,"origin":[320.0,240.0],"zoom":2.0,"orientation":"Deg90"}"#).unwrap();
+/// let expected: IsoProjection = IsoProjection::builder()
+///     .half_tile(14.0, 7.0)
+///     .z_scale(8.0)
+///     .origin(Vec2::new(320.0, 240.0))
+///     .zoom(2.0)
+///     .orientation(Orientation::Deg90)
+///     .build()
+///     .unwrap();
+/// assert_eq!(legacy, expected);
+///
+/// let sheared: IsoProjection = IsoProjection::from_basis(Vec2::new(16.0, 8.0), Vec2::new(-12.0, 10.0), 16.0).unwrap();
+/// assert_eq!(serde_json::from_str::<IsoProjection>(&serde_json::to_string(&sheared).unwrap()).unwrap(), sheared);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IsoProjection<S: Scalar = f32> {
+    /// Half the width of an isometric tile in screen pixels.
+    half_tw: S,
+    
+    /// Half the height of an isometric tile in screen pixels.
+    half_th: S,
+    
+    /// Screen basis vectors of the world x and y axes, before orientation, y axis and mirroring.
+    basis: S::Mat2,
+    
     /// The 2x2 matrix for the XY part of the isometric projection.
-    iso_matrix_2d: Mat2,
+    iso_matrix_2d: S::Mat2,
     
     /// The inverse of the 2x2 matrix.
-    inv_iso_matrix_2d: Mat2,
+    inv_iso_matrix_2d: S::Mat2,
     
     /// Scalar for Z-axis scaling (world_z to screen_z).
-    z_scale: f32,
+    z_scale: S,
     
     /// Inverse scalar for Z-axis scaling (screen_z to world_z).
-    inv_z_scale: f32,
+    inv_z_scale: S,
+    
+    /// Screen position that the world origin maps to.
+    origin: S::Vec2,
+    
+    /// Scale applied to the origin offset only, for layers that pan slower or faster.
+    parallax: S,
+    
+    /// Screen y offset of the whole layer in pixels, unaffected by zoom and parallax.
+    layer_offset: S,
+    
+    /// Uniform scale applied to screen coordinates.
+    zoom: S,
+    
+    /// Inverse of the zoom, precomputed for screen to world conversions.
+    inv_zoom: S,
+    
+    /// How integer grid x/y are laid out on screen.
+    layout: Layout,
+    
+    /// Rotation of the world x/y before projection, folded into the 2x2 matrix.
+    orientation: Orientation,
+    
+    /// Which way screen +y points, folded into the 2x2 matrix.
+    y_axis: YAxis,
+    
+    /// Whether screen x is negated to flip the map horizontally, folded into the 2x2 matrix.
+    mirror_x: bool,
+    
+    /// The point of a tile's diamond that screen positions refer to.
+    anchor: Anchor,
+}
+
+/// An [`IsoProjection`] computing in `f32`.
+pub type IsoProjectionF32 = IsoProjection<f32>;
+
+/// An [`IsoProjection`] computing in `f64`.
+pub type IsoProjectionF64 = IsoProjection<f64>;
+
+/// The projection of [`IsoProjection::new::<14, 14>`](IsoProjection::new), used across the examples.
+///
+/// Tiles are 28 pixels wide and 14 pixels high, a 2:1 diamond, with a z scale of
+/// 14 pixels per level and every other option at its default.
+///
+/// # Examples
+///
+/// ```
+/// use glam::{ IVec3, Vec3 };
+/// use spriso::IsoProjection;
+///
+/// let proj: IsoProjection = IsoProjection::default();
+///
+/// assert_eq!(proj, IsoProjection::new::<14, 14>());
+/// assert_eq!(proj.world_to_screen(IVec3::new(1, 0, 1)), Vec3::new(14.0, 7.0, 14.0));
+/// ```
+impl<S: Scalar> Default for IsoProjection<S> {
+    fn default() -> Self {
+        Self::from_dims(S::from_i32(14), S::from_i32(14))
+    }
+}
+
+/// Creates a projection from `(half_tw, half_th)` like [`IsoProjection::from_dims`].
+///
+/// # Panics
+///
+/// Panics under the same conditions as [`IsoProjection::from_dims`].
+///
+/// # Examples
+///
+/// ```
+/// use spriso::IsoProjection;
+///
+/// let proj: IsoProjection = IsoProjection::from((14.0, 14.0));
+///
+/// assert_eq!(proj, IsoProjection::from_dims(14.0, 14.0));
+/// ```
+impl<S: Scalar> From<(S, S)> for IsoProjection<S> {
+    fn from((half_tw, half_th): (S, S)) -> Self {
+        Self::from_dims(half_tw, half_th)
+    }
+}
+
+/// Creates a projection from integer `(half_tw, half_th)` like [`IsoProjection::from_dims`].
+///
+/// # Panics
+///
+/// Panics under the same conditions as [`IsoProjection::from_dims`].
+///
+/// # Examples
+///
+/// ```
+/// use spriso::IsoProjection;
+///
+/// let dims: [(u32, u32); 2] = [(14, 14), (16, 8)];
+/// let projs: Vec<IsoProjection> = dims.into_iter().map(IsoProjection::from).collect();
+///
+/// assert_eq!(projs, [IsoProjection::new::<14, 14>(), IsoProjection::new::<16, 8>()]);
+/// ```
+impl<S: Scalar> From<(u32, u32)> for IsoProjection<S> {
+    fn from((half_tw, half_th): (u32, u32)) -> Self {
+        Self::from_dims(S::from_i64(i64::from(half_tw)), S::from_i64(i64::from(half_th)))
+    }
 }
 
-impl IsoProjection {
+impl IsoProjection<f32> {
     /// Create a new projection struct to convert between world and screen.
     ///
+    /// # Panics
+    ///
+    /// Panics if either dimension is zero, see [`IsoProjection::new_const`].
+    ///
     /// # Type Parameters
     ///
     /// * `HALF_TW`: Half the width of an isometric tile in screen pixels.
@@ -31,63 +244,2058 @@ impl IsoProjection {
     ///
     /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
     /// ```
-    pub fn new<const HALF_TW: u32, const HALF_TH: u32>() -> Self {
-        let iso_matrix_2d = Mat2::from_cols(
-            Vec2::new(1.0 * (HALF_TW as f32), 0.5 * (HALF_TH as f32)),
-            Vec2::new(-1.0 * (HALF_TW as f32), 0.5 * (HALF_TH as f32))
-        );
-        let inv_iso_matrix_2d = iso_matrix_2d.inverse();
-        let z_scale: f32 = HALF_TH as f32;
+    pub const fn new<const HALF_TW: u32, const HALF_TH: u32>() -> Self {
+        Self::new_const(HALF_TW as f32, HALF_TH as f32)
+    }
+
+    /// Create a new projection struct in a `const` context, e.g. for a `static`.
+    ///
+    /// Produces exactly the same projection as [`IsoProjection::from_dims`] for equal inputs,
+    /// with the 2x2 inverse computed in closed form.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a zero or non-finite `half_tw` or `half_th` makes the projection
+    /// matrix singular, or either is negative. In a `const` or `static` initializer
+    /// this fails the build.
+    ///
+    /// # Arguments
+    ///
+    /// * `half_tw`: Half the width of an isometric tile in screen pixels.
+    /// * `half_th`: Half the height of an isometric tile in screen pixels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spriso::IsoProjection;
+    ///
+    /// static PROJ: IsoProjection = IsoProjection::new_const(14.0, 14.0);
+    ///
+    /// assert_eq!(PROJ, IsoProjection::from_dims(14.0, 14.0));
+    /// ```
+    pub const fn new_const(half_tw: f32, half_th: f32) -> Self {
+        let x_axis: Vec2 = Vec2::new(half_tw, 0.5 * half_th);
+        let y_axis: Vec2 = Vec2::new(-half_tw, 0.5 * half_th);
+
+        let det: f32 = x_axis.x * y_axis.y - x_axis.y * y_axis.x;
+        assert!(det != 0.0 && det.is_finite(), "invalid tile dimensions: projection matrix is degenerate");
+        assert!(half_th != 0.0 && half_th.is_finite(), "invalid tile dimensions: z scale is degenerate");
+        assert!(half_tw > 0.0 && half_th > 0.0, "invalid tile dimensions: tile has no positive width or height");
+
+        let inv_det: f32 = 1.0 / det;
+
+        Self {
+            half_tw,
+            half_th,
+            basis: Mat2::from_cols(x_axis, y_axis),
+            iso_matrix_2d: Mat2::from_cols(x_axis, y_axis),
+            inv_iso_matrix_2d: Mat2::from_cols(
+                Vec2::new(y_axis.y * inv_det, x_axis.y * -inv_det),
+                Vec2::new(y_axis.x * -inv_det, x_axis.x * inv_det)
+            ),
+            z_scale: half_th,
+            inv_z_scale: 1.0 / half_th,
+            origin: Vec2::ZERO,
+            parallax: 1.0,
+            layer_offset: 0.0,
+            zoom: 1.0,
+            inv_zoom: 1.0,
+            layout: Layout::Diamond,
+            orientation: Orientation::Deg0,
+            y_axis: YAxis::Down,
+            mirror_x: false,
+            anchor: Anchor::Center,
+        }
+    }
+}
+
+impl<S: Scalar> IsoProjection<S> {
+    /// Create a new projection struct from tile dimensions only known at runtime.
+    ///
+    /// Produces exactly the same projection as [`IsoProjection::new`] for equal inputs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a zero or non-finite `half_tw` or `half_th` makes the projection
+    /// matrix singular, since every screen to world conversion would return garbage,
+    /// or either is negative, which would flip the tile's anchors.
+    /// Use [`IsoProjection::try_from_dims`] to handle this as an error instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `half_tw`: Half the width of an isometric tile in screen pixels.
+    /// * `half_th`: Half the height of an isometric tile in screen pixels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::IVec3;
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::from_dims(14.0, 14.0);
+    /// let const_proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// let pos: IVec3 = IVec3::new(10, 20, 30);
+    /// assert_eq!(proj.world_to_screen(pos), const_proj.world_to_screen(pos));
+    /// ```
+    pub fn from_dims(half_tw: S, half_th: S) -> Self {
+        match Self::try_from_dims(half_tw, half_th) {
+            Ok(proj) => proj,
+            Err(err) => panic!("invalid tile dimensions {half_tw:?}x{half_th:?}: {err}"),
+        }
+    }
+
+    /// Create a new projection struct from runtime tile dimensions, checking that it can be inverted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError::DegenerateMatrix`] if the projection matrix has a zero
+    /// or non-finite determinant, e.g. because a dimension is zero, and
+    /// [`ProjectionError::DegenerateBasis`] if a dimension is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spriso::{ IsoProjection, ProjectionError };
+    ///
+    /// assert!(IsoProjection::<f32>::try_from_dims(14.0, 14.0).is_ok());
+    /// assert_eq!(IsoProjection::<f32>::try_from_dims(0.0, 14.0), Err(ProjectionError::DegenerateMatrix));
+    /// assert_eq!(IsoProjection::<f32>::try_from_dims(-14.0, 14.0), Err(ProjectionError::DegenerateBasis));
+    /// assert_eq!(IsoProjection::<f32>::try_from_dims(14.0, f32::NAN), Err(ProjectionError::DegenerateMatrix));
+    /// ```
+    pub fn try_from_dims(half_tw: S, half_th: S) -> Result<Self, ProjectionError> {
+        Self::try_from_dims_with_z_scale(half_tw, half_th, half_th)
+    }
+
+    /// Create a new projection struct from the full tile size in screen pixels.
+    ///
+    /// The drawn diamond is `width` pixels wide and `height` pixels high. Since the
+    /// matrix already draws a diamond `half_th` pixels high, this is equivalent to
+    /// [`IsoProjection::from_dims`] with only the width halved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a zero or non-finite `width` or `height` makes the projection matrix singular,
+    /// or either is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::from_tile_size(28.0, 14.0);
+    /// assert_eq!(proj, IsoProjection::new::<14, 14>());
+    ///
+    /// let (min, max): (Vec2, Vec2) = proj.tile_screen_aabb(IVec3::ZERO);
+    /// assert_eq!(max - min, Vec2::new(28.0, 14.0));
+    /// ```
+    pub fn from_tile_size(width: S, height: S) -> Self {
+        Self::from_dims(width * S::HALF, height)
+    }
+
+    /// Create a new projection struct from the full tile size, optionally requiring 2:1 tiles.
+    ///
+    /// With `strict` set the width must be exactly twice the height, catching sizes
+    /// mistyped by hand for pixel art drawn at the standard 2:1 ratio.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError::AspectRatio`] if `strict` is set and `width` isn't
+    /// exactly twice `height`, [`ProjectionError::DegenerateMatrix`] if the
+    /// projection matrix has a zero or non-finite determinant and
+    /// [`ProjectionError::DegenerateBasis`] if either size is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2 };
+    /// use spriso::{ IsoProjection, ProjectionError };
+    ///
+    /// let proj: IsoProjection = IsoProjection::try_from_tile_size(64.0, 32.0, true).unwrap();
+    /// let (min, max): (Vec2, Vec2) = proj.tile_screen_aabb(IVec3::ZERO);
+    /// assert_eq!(max - min, Vec2::new(64.0, 32.0));
+    ///
+    /// assert_eq!(IsoProjection::<f32>::try_from_tile_size(64.0, 30.0, true), Err(ProjectionError::AspectRatio));
+    /// assert_eq!(IsoProjection::<f32>::try_from_tile_size(64.0, 16.0, true), Err(ProjectionError::AspectRatio));
+    /// assert!(IsoProjection::<f32>::try_from_tile_size(64.0, 30.0, false).is_ok());
+    /// ```
+    pub fn try_from_tile_size(width: S, height: S, strict: bool) -> Result<Self, ProjectionError> {
+        if strict && width != height + height {
+            return Err(ProjectionError::AspectRatio);
+        }
+
+        Self::try_from_dims(width * S::HALF, height)
+    }
+
+    /// Create a new projection struct with a z elevation step independent of the tile height.
+    ///
+    /// [`IsoProjection::from_dims`] draws each world z level `half_th` pixels higher.
+    /// This draws it `z_scale` pixels higher instead, so stacked tiles can be taller
+    /// or shorter than the floor diamond's proportions suggest.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the projection matrix is singular, a dimension is negative or `z_scale` is zero or non-finite.
+    /// Use [`IsoProjection::try_from_dims_with_z_scale`] to handle this as an error instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `half_tw`: Half the width of an isometric tile in screen pixels.
+    /// * `half_th`: Half the height of an isometric tile in screen pixels.
+    /// * `z_scale`: Screen pixels per world z level, before zoom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::from_dims_with_z_scale(32.0, 32.0, 20.0);
+    /// let pos: IVec3 = IVec3::new(1, 2, 3);
+    ///
+    /// assert_eq!(proj.world_to_screen(IVec3::new(0, 0, 3)), Vec3::new(0.0, 0.0, 60.0));
+    /// assert_eq!(proj.screen_to_world(proj.world_to_screen(pos)), pos);
+    /// ```
+    pub fn from_dims_with_z_scale(half_tw: S, half_th: S, z_scale: S) -> Self {
+        match Self::try_from_dims_with_z_scale(half_tw, half_th, z_scale) {
+            Ok(proj) => proj,
+            Err(err) => panic!("invalid tile dimensions {half_tw:?}x{half_th:?} with z scale {z_scale:?}: {err}"),
+        }
+    }
+
+    /// Create a new projection struct with an independent z elevation step, checking that it can be inverted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError::DegenerateMatrix`] if the projection matrix has a zero
+    /// or non-finite determinant, or `z_scale` is zero or non-finite, and
+    /// [`ProjectionError::DegenerateBasis`] if a dimension is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spriso::{ IsoProjection, ProjectionError };
+    ///
+    /// assert!(IsoProjection::<f32>::try_from_dims_with_z_scale(32.0, 32.0, 20.0).is_ok());
+    /// assert_eq!(IsoProjection::<f32>::try_from_dims_with_z_scale(32.0, 32.0, 0.0), Err(ProjectionError::DegenerateMatrix));
+    /// ```
+    pub fn try_from_dims_with_z_scale(half_tw: S, half_th: S, z_scale: S) -> Result<Self, ProjectionError> {
+        Self::from_parts(Self::tile_basis(half_tw, half_th), z_scale).validate()
+    }
+
+    /// Create a new projection struct from the screen basis vectors of the world x and y axes.
+    ///
+    /// This is the most general constructor: `x_axis` and `y_axis` are the screen
+    /// pixels one step along world x and y moves a tile's center, so dimetric,
+    /// non 2:1 and sheared projections can be encoded. The other constructors use
+    /// `(half_tw, half_th / 2)` and `(-half_tw, half_th / 2)`. The tile's diamond
+    /// spans the parallelogram between these vectors. [`IsoProjection::half_tile_width`]
+    /// is half of `x_axis.x - y_axis.x` and [`IsoProjection::half_tile_height`] is
+    /// `x_axis.y + y_axis.y`, the parallelogram's horizontal and vertical diagonals
+    /// when the basis isn't sheared. Orientation, y axis and mirroring are applied
+    /// on top of the basis as usual.
+    ///
+    /// Sheared bases break the screen order [`IsoProjection::depth_key`] relies on,
+    /// so sort their tiles by [`IsoProjection::screen_depth_key`] instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError::DegenerateMatrix`] if the basis vectors are parallel
+    /// or non-finite, or `z_scale` is zero or non-finite, and [`ProjectionError::DegenerateBasis`]
+    /// if the derived half-tile width or height isn't positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2, Vec3 };
+    /// use spriso::{ IsoProjection, ProjectionError };
+    ///
+    /// // A sheared projection whose y axis leans less than its x axis.
+    /// let proj: IsoProjection = IsoProjection::from_basis(Vec2::new(16.0, 8.0), Vec2::new(-12.0, 10.0), 16.0).unwrap();
+    /// let pos: IVec3 = IVec3::new(3, -2, 1);
+    ///
+    /// assert_eq!(proj.world_to_screen(pos), Vec3::new(72.0, 4.0, 16.0));
+    /// assert_eq!(proj.screen_to_world(proj.world_to_screen(pos)), pos);
+    /// assert_eq!(proj.tile_at_screen(proj.world_to_screen_flat(pos), 1), pos);
+    ///
+    /// assert_eq!(
+    ///     IsoProjection::<f32>::from_basis(Vec2::new(14.0, 7.0), Vec2::new(-14.0, 7.0), 14.0),
+    ///     Ok(IsoProjection::new::<14, 14>())
+    /// );
+    /// assert_eq!(IsoProjection::<f32>::from_basis(Vec2::new(2.0, 1.0), Vec2::new(4.0, 2.0), 14.0), Err(ProjectionError::DegenerateMatrix));
+    /// assert_eq!(IsoProjection::<f32>::from_basis(Vec2::new(16.0, 8.0), Vec2::new(16.0, -8.0), 14.0), Err(ProjectionError::DegenerateBasis));
+    /// ```
+    pub fn from_basis(x_axis: S::Vec2, y_axis: S::Vec2, z_scale: S) -> Result<Self, ProjectionError> {
+        Self::from_parts(S::Mat2::from_cols(x_axis, y_axis), z_scale).validate()
+    }
+
+    /// Builds the projection and all derived state from its source parameters.
+    pub(crate) fn from_parts(basis: S::Mat2, z_scale: S) -> Self {
+        let iso_matrix_2d: S::Mat2 = Self::tile_matrix(basis, Orientation::Deg0, YAxis::Down, false);
+        let inv_iso_matrix_2d: S::Mat2 = iso_matrix_2d.inverse();
 
         Self {
+            half_tw: (basis.x_axis().x() - basis.y_axis().x()) * S::HALF,
+            half_th: basis.x_axis().y() + basis.y_axis().y(),
+            basis,
             iso_matrix_2d,
             inv_iso_matrix_2d,
             z_scale,
-            inv_z_scale: 1.0 / z_scale,
+            inv_z_scale: S::ONE / z_scale,
+            origin: S::Vec2::new(S::ZERO, S::ZERO),
+            parallax: S::ONE,
+            layer_offset: S::ZERO,
+            zoom: S::ONE,
+            inv_zoom: S::ONE,
+            layout: Layout::Diamond,
+            orientation: Orientation::Deg0,
+            y_axis: YAxis::Down,
+            mirror_x: false,
+            anchor: Anchor::Center,
         }
     }
 
-    /// Converts 3d grid positions to their corresponding screen position.
+    /// Returns the basis of the standard projection for a tile size.
+    pub(crate) fn tile_basis(half_tw: S, half_th: S) -> S::Mat2 {
+        S::Mat2::from_cols(S::Vec2::new(half_tw, S::HALF * half_th), S::Vec2::new(-half_tw, S::HALF * half_th))
+    }
+
+    /// Builds the 2x2 projection matrix for a basis, orientation, y axis direction and horizontal mirroring.
+    fn tile_matrix(basis: S::Mat2, orientation: Orientation, y_axis: YAxis, mirror_x: bool) -> S::Mat2 {
+        let (x_col, y_col): (S::Vec2, S::Vec2) = orientation.rotate_axes(basis.x_axis(), basis.y_axis());
+
+        S::Mat2::from_cols(Self::screen_axes(x_col, y_axis, mirror_x), Self::screen_axes(y_col, y_axis, mirror_x))
+    }
+
+    /// Moves a y-down, unmirrored screen vector to the projection's y axis and mirroring.
+    fn screen_axes(vec: S::Vec2, y_axis: YAxis, mirror_x: bool) -> S::Vec2 {
+        S::Vec2::new(if mirror_x { -vec.x() } else { vec.x() }, y_axis.orient(vec.y()))
+    }
+
+    /// Rebuilds the matrix and its inverse after the orientation, y axis or mirroring changed.
+    fn rebuild_matrix(&mut self) {
+        self.iso_matrix_2d = Self::tile_matrix(self.basis, self.orientation, self.y_axis, self.mirror_x);
+        self.inv_iso_matrix_2d = self.iso_matrix_2d.inverse();
+    }
+
+    /// Changes the tile size in place, e.g. when the UI scale changes.
+    ///
+    /// The matrix, its inverse and the z scale are rebuilt while origin, zoom,
+    /// parallax, layout, orientation, y axis, mirroring and anchor are kept. The z scale is
+    /// scaled by the same factor as the half-tile height, so its ratio to the tile
+    /// height stays what it was. A basis given to [`IsoProjection::from_basis`] is
+    /// scaled horizontally and vertically by the same factors as the half-tile size,
+    /// keeping its shear. Any screen positions computed before the call are
+    /// stale and must be recomputed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a zero or non-finite `half_tw` or `half_th` makes the projection
+    /// matrix singular, or either is negative. The projection is left unchanged in that case.
     ///
     /// # Examples
     ///
     /// ```
-    /// use glam::{ IVec3, Vec3 };
+    /// use glam::{ IVec3, Vec2 };
     /// use spriso::IsoProjection;
     ///
-    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let mut proj: IsoProjection = IsoProjection::from_dims_with_z_scale(14.0, 14.0, 7.0)
+    ///     .with_origin(Vec2::new(50.0, 50.0));
+    /// proj.set_half_tile(28.0, 28.0);
     ///
-    /// let pos: IVec3 = IVec3::new(10, 20, 30);
-    /// let screen_pos: Vec3 = proj.world_to_screen(pos);
+    /// assert_eq!(proj, IsoProjection::from_dims_with_z_scale(28.0, 28.0, 14.0).with_origin(Vec2::new(50.0, 50.0)));
     ///
-    /// assert!(screen_pos.x != 0.0);
-    /// assert!(screen_pos.y != 0.0);
-    /// assert!(screen_pos.z != 0.0);
+    /// // Standard tiles are rebuilt exactly, whatever the ratio between the sizes.
+    /// let mut resized: IsoProjection = IsoProjection::from_dims(14.0, 9.0);
+    /// resized.set_half_tile(13.0, 7.0);
+    /// assert_eq!(resized, IsoProjection::from_dims(13.0, 7.0));
+    ///
+    /// let pos: IVec3 = IVec3::new(-3, 8, 2);
+    /// assert_eq!(proj.screen_to_world(proj.world_to_screen(pos)), pos);
     /// ```
-    pub fn world_to_screen(&self, world_pos: IVec3) -> Vec3 {
-        let world_vec_2d: Vec2 = Vec2::new(world_pos.x as f32, world_pos.y as f32);
-        let screen_vec_2d: Vec2 = self.iso_matrix_2d * world_vec_2d;
+    pub fn set_half_tile(&mut self, half_tw: S, half_th: S) {
+        let scale = |col: S::Vec2| S::Vec2::new(col.x() * half_tw / self.half_tw, col.y() * half_th / self.half_th);
+
+        let mut proj: IsoProjection<S> = *self;
+        proj.half_tw = half_tw;
+        proj.half_th = half_th;
+        // Rebuild standard bases and z scales outright, since scaling by a ratio can be off by an ulp.
+        proj.basis = if self.basis == Self::tile_basis(self.half_tw, self.half_th) {
+            Self::tile_basis(half_tw, half_th)
+        } else {
+            S::Mat2::from_cols(scale(self.basis.x_axis()), scale(self.basis.y_axis()))
+        };
+        proj.z_scale = if self.z_scale == self.half_th { half_th } else { self.z_scale * half_th / self.half_th };
+        proj.inv_z_scale = S::ONE / proj.z_scale;
+        proj.rebuild_matrix();
 
-        Vec3::new(screen_vec_2d.x, screen_vec_2d.y, (world_pos.z as f32) * self.z_scale)
+        match proj.validate() {
+            Ok(proj) => *self = proj,
+            Err(err) => panic!("invalid tile dimensions {half_tw:?}x{half_th:?}: {err}"),
+        }
     }
 
-    /// Converts screen positions to their corresponding 3d grid positions.
-    pub fn screen_to_world(&self, screen_pos: Vec3) -> IVec3 {
-        let screen_vec_2d: Vec2 = Vec2::new(screen_pos.x, screen_pos.y);
-        let world_vec_2d: Vec2 = self.inv_iso_matrix_2d * screen_vec_2d;
-
-        IVec3::new(
-            world_vec_2d.x.round() as i32,
-            world_vec_2d.y.round() as i32,
-            (screen_pos.z * self.inv_z_scale).round() as i32
-        )
+    /// Returns a copy of the projection with the tile size and z scale multiplied by `factor`.
+    ///
+    /// The matrix and its inverse are rebuilt for the new size, while origin, zoom,
+    /// parallax, layer offset, layout, orientation, y axis, mirroring and anchor are kept, so a
+    /// zoomed minimap can be derived from the main view's projection.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a zero or non-finite `factor` makes the projection matrix singular,
+    /// or a negative one gives the tile a negative size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::from_dims_with_z_scale(14.0, 14.0, 7.0);
+    /// let minimap: IsoProjection = proj.scaled(0.25).with_origin(Vec2::new(40.0, 40.0));
+    ///
+    /// assert_eq!(minimap, IsoProjection::from_dims_with_z_scale(3.5, 3.5, 1.75).with_origin(Vec2::new(40.0, 40.0)));
+    ///
+    /// let pos: IVec3 = IVec3::new(-3, 8, 2);
+    /// assert_eq!(minimap.screen_to_world(minimap.world_to_screen(pos)), pos);
+    /// ```
+    pub fn scaled(&self, factor: S) -> Self {
+        let mut proj: IsoProjection<S> = *self;
+        proj.half_tw = self.half_tw * factor;
+        proj.half_th = self.half_th * factor;
+        proj.basis = S::Mat2::from_cols(self.basis.x_axis() * factor, self.basis.y_axis() * factor);
+        proj.z_scale = self.z_scale * factor;
+        proj.inv_z_scale = S::ONE / proj.z_scale;
+        proj.rebuild_matrix();
+
+        match proj.validate() {
+            Ok(proj) => proj,
+            Err(err) => panic!("invalid scale factor {factor:?}: {err}"),
+        }
     }
 
-    /// Converts 3d grid float position to its corresponding screen position.
-    pub fn world_float_to_screen(&self, world_float_pos: Vec3) -> Vec3 {
-        let world_vec_2d: Vec2 = Vec2::new(world_float_pos.x, world_float_pos.y);
-        let screen_vec_2d: Vec2 = self.iso_matrix_2d * world_vec_2d;
+    /// Returns the projection with the world origin moved to `offset` on screen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2, Vec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>()
+    ///     .with_origin(Vec2::new(320.0, 240.0));
+    ///
+    /// assert_eq!(proj.world_to_screen(IVec3::ZERO), Vec3::new(320.0, 240.0, 0.0));
+    /// ```
+    pub fn with_origin(mut self, offset: S::Vec2) -> Self {
+        self.origin = offset;
+        self
+    }
+
+    /// Returns the projection with integer grid positions interpreted in `layout`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec3 };
+    /// use spriso::{ IsoProjection, Layout };
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>().with_layout(Layout::Staggered);
+    ///
+    /// assert_eq!(proj.world_to_screen(IVec3::new(1, 0, 0)), Vec3::new(28.0, 0.0, 0.0));
+    /// assert_eq!(proj.world_to_screen(IVec3::new(0, 1, 0)), Vec3::new(14.0, 7.0, 0.0));
+    /// assert_eq!(proj.world_to_screen(IVec3::new(0, 2, 0)), Vec3::new(0.0, 14.0, 0.0));
+    ///
+    /// let pos: IVec3 = IVec3::new(-3, -7, 2);
+    /// assert_eq!(proj.screen_to_world(proj.world_to_screen(pos)), pos);
+    /// ```
+    pub fn with_layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Returns how integer grid x/y are laid out on screen.
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// Returns the projection with the world x/y rotated by `orientation`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec3 };
+    /// use spriso::{ IsoProjection, Orientation };
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>().with_orientation(Orientation::Deg90);
+    ///
+    /// assert_eq!(proj.world_to_screen(IVec3::new(1, 0, 2)), Vec3::new(-14.0, 7.0, 28.0));
+    /// ```
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.set_orientation(orientation);
+        self
+    }
+
+    /// Rotates the world x/y by `orientation`, replacing the previous orientation.
+    pub fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+        self.rebuild_matrix();
+    }
+
+    /// Returns the rotation applied to the world x/y before projection.
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// Returns the projection with screen +y pointing in the `y_axis` direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2, Vec3 };
+    /// use spriso::{ IsoProjection, YAxis };
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>().with_y_axis(YAxis::Up);
+    /// let pos: IVec3 = IVec3::new(1, 2, 3);
+    ///
+    /// assert_eq!(proj.world_to_screen(IVec3::new(1, 0, 0)), Vec3::new(14.0, -7.0, 0.0));
+    /// assert_eq!(proj.screen_to_world(proj.world_to_screen(pos)), pos);
+    ///
+    /// // Elevated tiles are drawn towards +y.
+    /// assert_eq!(proj.tile_at_screen(Vec2::new(0.0, 14.0), 1), IVec3::new(0, 0, 1));
+    /// ```
+    pub fn with_y_axis(mut self, y_axis: YAxis) -> Self {
+        self.y_axis = y_axis;
+        self.rebuild_matrix();
+        self
+    }
+
+    /// Returns which way screen +y points.
+    pub fn y_axis(&self) -> YAxis {
+        self.y_axis
+    }
+
+    /// Returns the projection flipped horizontally if `mirror_x` is `true`.
+    ///
+    /// Screen x of every position is negated, swapping east and west for art
+    /// drawn with the opposite handedness. The diamonds are symmetric, so tile
+    /// shapes and hit testing are unchanged apart from the flip.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2, Vec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>().with_mirror_x(true);
+    /// let pos: IVec3 = IVec3::new(1, 2, 3);
+    ///
+    /// assert_eq!(proj.world_to_screen(IVec3::new(1, 0, 0)), Vec3::new(-14.0, 7.0, 0.0));
+    /// assert_eq!(proj.screen_to_world(proj.world_to_screen(pos)), pos);
+    /// assert_eq!(proj.tile_at_screen(Vec2::new(-14.0, 7.0), 0), IVec3::new(1, 0, 0));
+    /// ```
+    pub fn with_mirror_x(mut self, mirror_x: bool) -> Self {
+        self.mirror_x = mirror_x;
+        self.rebuild_matrix();
+        self
+    }
+
+    /// Returns whether screen x is negated to flip the map horizontally.
+    pub fn mirror_x(&self) -> bool {
+        self.mirror_x
+    }
+
+    /// Returns the projection with screen positions referring to the `anchor` point of each tile.
+    ///
+    /// The anchor moves the screen positions of [`IsoProjection::world_to_screen`] and
+    /// [`IsoProjection::world_to_screen_f`] to that point of the diamond, and their
+    /// inverses remove the same offset, so round trips hold for every anchor. Tile
+    /// geometry and hit tests such as [`IsoProjection::tile_at_screen`] aren't affected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec3 };
+    /// use spriso::{ Anchor, IsoProjection };
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>().with_anchor(Anchor::Bottom);
+    /// let pos: IVec3 = IVec3::new(2, -1, 1);
+    ///
+    /// assert_eq!(proj.world_to_screen(IVec3::ZERO), Vec3::new(0.0, 7.0, 0.0));
+    /// assert_eq!(proj.screen_to_world(proj.world_to_screen(pos)), pos);
+    /// ```
+    pub fn with_anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Returns the point of a tile's diamond that screen positions refer to.
+    pub fn anchor(&self) -> Anchor {
+        self.anchor
+    }
+
+    /// Turns the view a quarter clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::IVec3;
+    /// use spriso::{ IsoProjection, Orientation };
+    ///
+    /// let mut proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let pos: IVec3 = IVec3::new(7, -3, 4);
+    ///
+    /// for _ in 0..4 {
+    ///     proj.rotate_cw();
+    ///     assert_eq!(proj.screen_to_world(proj.world_to_screen(pos)), pos);
+    ///     assert_eq!(proj.world_to_screen(pos).z, 56.0);
+    /// }
+    ///
+    /// assert_eq!(proj.orientation(), Orientation::Deg0);
+    /// ```
+    pub fn rotate_cw(&mut self) {
+        self.set_orientation(self.orientation.rotate_cw());
+    }
+
+    /// Turns the view a quarter counter-clockwise.
+    pub fn rotate_ccw(&mut self) {
+        self.set_orientation(self.orientation.rotate_ccw());
+    }
+
+    /// Moves the world origin to `offset` on screen, e.g. to pan the camera.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2, Vec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let mut proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// proj.set_origin(Vec2::new(-17.5, 3.25));
+    ///
+    /// let pos: IVec3 = IVec3::new(-4, 9, 2);
+    /// assert_eq!(proj.screen_to_world(proj.world_to_screen(pos)), pos);
+    /// ```
+    pub fn set_origin(&mut self, offset: S::Vec2) {
+        self.origin = offset;
+    }
+
+    /// Returns the screen position that the world origin maps to.
+    pub fn origin(&self) -> S::Vec2 {
+        self.origin
+    }
+
+    /// Sets the factor the origin offset is scaled by before it's applied.
+    ///
+    /// Tile geometry is unaffected, so a background layer can share the tile size
+    /// of the main grid and still pan at a fraction of its speed. With the default
+    /// of one the origin is applied as is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parallax` is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2, Vec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let camera: Vec2 = Vec2::new(100.0, -40.0);
+    /// let main: IsoProjection = IsoProjection::new::<14, 14>().with_origin(camera);
+    /// let mut background: IsoProjection = main;
+    /// background.set_parallax(0.5);
+    ///
+    /// assert_eq!(main.world_to_screen(IVec3::ZERO), Vec3::new(100.0, -40.0, 0.0));
+    /// assert_eq!(background.world_to_screen(IVec3::ZERO), Vec3::new(50.0, -20.0, 0.0));
+    ///
+    /// let pos: IVec3 = IVec3::new(5, -2, 1);
+    /// assert_eq!(background.screen_to_world(background.world_to_screen(pos)), pos);
+    /// ```
+    pub fn set_parallax(&mut self, parallax: S) {
+        assert!(parallax.is_finite(), "parallax must be finite, got {parallax:?}");
+
+        self.parallax = parallax;
+    }
+
+    /// Returns the factor the origin offset is scaled by before it's applied.
+    pub fn parallax(&self) -> S {
+        self.parallax
+    }
+
+    /// Moves everything this projection draws by `offset` screen pixels along y.
+    ///
+    /// Layers such as floors, objects and roofs can share one tile geometry and
+    /// still be nudged by amounts that aren't whole z levels. The offset isn't
+    /// zoomed and applies to every conversion, so picking within a layer still
+    /// round-trips.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2, Vec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let mut roof: IsoProjection = IsoProjection::new::<14, 14>();
+    /// roof.set_layer_offset(-10.0);
+    ///
+    /// let pos: IVec3 = IVec3::new(2, 3, 1);
+    ///
+    /// assert_eq!(roof.world_to_screen(IVec3::ZERO), Vec3::new(0.0, -10.0, 0.0));
+    /// assert_eq!(roof.screen_to_world(roof.world_to_screen(pos)), pos);
+    /// assert_eq!(roof.tile_at_screen(Vec2::new(0.0, -10.0), 0), IVec3::ZERO);
+    /// ```
+    pub fn set_layer_offset(&mut self, offset: S) {
+        assert!(offset.is_finite(), "layer offset must be finite, got {offset:?}");
+
+        self.layer_offset = offset;
+    }
+
+    /// Returns the screen y offset of everything this projection draws.
+    pub fn layer_offset(&self) -> S {
+        self.layer_offset
+    }
+
+    /// Sets the uniform zoom applied to screen x, y and z.
+    ///
+    /// The origin offset is not zoomed, so zooming happens around the screen origin.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `zoom` is zero or not finite, since the inverse would be undefined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let mut proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let pos: IVec3 = IVec3::new(3, 1, 2);
+    /// let screen_pos: Vec3 = proj.world_to_screen(pos);
+    ///
+    /// proj.set_zoom(2.0);
+    ///
+    /// assert_eq!(proj.world_to_screen(pos), screen_pos * 2.0);
+    /// assert_eq!(proj.screen_to_world(screen_pos * 2.0), pos);
+    /// ```
+    pub fn set_zoom(&mut self, zoom: S) {
+        assert!(zoom != S::ZERO && zoom.is_finite(), "zoom must be finite and non-zero, got {zoom:?}");
+
+        self.zoom = zoom;
+        self.inv_zoom = S::ONE / zoom;
+    }
+
+    /// Returns the uniform zoom applied to screen coordinates.
+    pub fn zoom(&self) -> S {
+        self.zoom
+    }
+
+    /// Returns the 2x2 matrix projecting world x/y to screen x/y, before zoom and origin.
+    ///
+    /// Its columns are the screen space basis vectors of the world x and y axes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ Mat2, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// assert_eq!(proj.iso_matrix().x_axis, Vec2::new(14.0, 7.0));
+    /// assert!((proj.iso_matrix() * proj.inv_iso_matrix()).abs_diff_eq(Mat2::IDENTITY, 1e-6));
+    /// ```
+    pub fn iso_matrix(&self) -> S::Mat2 {
+        self.iso_matrix_2d
+    }
+
+    /// Returns the inverse of [`IsoProjection::iso_matrix`].
+    pub fn inv_iso_matrix(&self) -> S::Mat2 {
+        self.inv_iso_matrix_2d
+    }
+
+    /// Returns the projection of world x/y to screen x/y as one affine transform.
+    ///
+    /// The transform holds the 2x2 matrix scaled by the zoom and the translation of
+    /// the origin, including the parallax and anchor, so applying it to a diamond grid
+    /// position gives the same x/y as [`IsoProjection::world_to_screen_f`]. The z
+    /// scale is a separate scalar and isn't included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ Affine2, Vec2, Vec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let mut proj: IsoProjection = IsoProjection::new::<14, 14>().with_origin(Vec2::new(320.0, 240.0));
+    /// proj.set_zoom(2.0);
+    ///
+    /// let affine: Affine2 = proj.as_affine2();
+    /// let screen_pos: Vec3 = proj.world_to_screen_f(Vec3::new(3.0, -1.5, 2.0));
+    ///
+    /// assert_eq!(affine.transform_point2(Vec2::new(3.0, -1.5)), screen_pos.truncate());
+    /// ```
+    pub fn as_affine2(&self) -> S::Affine2 {
+        let matrix2: S::Mat2 = S::Mat2::from_cols(self.iso_matrix_2d.x_axis() * self.zoom, self.iso_matrix_2d.y_axis() * self.zoom);
+
+        S::Affine2::from_mat2_translation(matrix2, self.screen_offset())
+    }
+
+    /// Returns [`IsoProjection::as_affine2`] as a 3x3 matrix for homogeneous 2D coordinates, e.g. for a shader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ Mat3, Vec2, Vec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>().with_origin(Vec2::new(10.0, 20.0));
+    /// let mat3: Mat3 = proj.as_mat3();
+    ///
+    /// assert_eq!(mat3 * Vec3::new(1.0, 0.0, 1.0), Vec3::new(24.0, 27.0, 1.0));
+    /// ```
+    pub fn as_mat3(&self) -> S::Mat3 {
+        self.as_affine2().to_mat3()
+    }
+
+    /// Returns half the width of an isometric tile in screen pixels, before zoom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::from_dims(16.0, 12.0);
+    ///
+    /// assert_eq!((proj.half_tile_width(), proj.half_tile_height()), (16.0, 12.0));
+    /// ```
+    pub fn half_tile_width(&self) -> S {
+        self.half_tw
+    }
+
+    /// Returns the `half_th` the projection was built with, before zoom.
+    ///
+    /// The matrix draws the diamond this many pixels high, so this is also [`IsoProjection::tile_height`].
+    pub fn half_tile_height(&self) -> S {
+        self.half_th
+    }
+
+    /// Returns the width of the drawn diamond in screen pixels, before zoom, as passed to [`IsoProjection::from_tile_size`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::from_tile_size(32.0, 12.0);
+    /// let (min, max): (Vec2, Vec2) = proj.tile_screen_aabb(IVec3::ZERO);
+    ///
+    /// assert_eq!(Vec2::new(proj.tile_width(), proj.tile_height()), max - min);
+    /// assert_eq!((IsoProjection::<f32>::default().tile_width(), IsoProjection::<f32>::default().tile_height()), (28.0, 14.0));
+    /// ```
+    pub fn tile_width(&self) -> S {
+        self.half_tw + self.half_tw
+    }
+
+    /// Returns the height of the drawn diamond in screen pixels, before zoom, as passed to [`IsoProjection::from_tile_size`].
+    pub fn tile_height(&self) -> S {
+        self.half_th
+    }
+
+    /// Sets how many screen pixels one world z level is drawn as, before zoom.
+    ///
+    /// The tile size is left alone, so this exaggerates or flattens terrain at
+    /// runtime. Any screen positions computed before the call are stale.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `z_scale` is zero or not finite, since the inverse would be undefined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let mut proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// proj.set_z_scale(28.0);
+    ///
+    /// let pos: IVec3 = IVec3::new(3, 1, 2);
+    ///
+    /// assert_eq!(proj.z_scale(), 28.0);
+    /// assert_eq!(proj.world_to_screen(pos), Vec3::new(28.0, 28.0, 56.0));
+    /// assert_eq!(proj.screen_to_world(proj.world_to_screen(pos)), pos);
+    /// ```
+    pub fn set_z_scale(&mut self, z_scale: S) {
+        assert!(z_scale != S::ZERO && z_scale.is_finite(), "z scale must be finite and non-zero, got {z_scale:?}");
+
+        self.z_scale = z_scale;
+        self.inv_z_scale = S::ONE / z_scale;
+    }
+
+    /// Returns the screen z per world z level, before zoom.
+    pub fn z_scale(&self) -> S {
+        self.z_scale
+    }
+
+    /// Converts 3d grid positions to their corresponding screen position.
+    ///
+    /// The returned x/y is the tile's [`Anchor`] point on the ground level, which is
+    /// the center of its diamond by default. The elevation is returned separately in z
+    /// rather than folded into y. Use [`IsoProjection::world_to_screen_center`] for
+    /// the diamond center whatever the anchor is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// let pos: IVec3 = IVec3::new(10, 20, 30);
+    /// let screen_pos: Vec3 = proj.world_to_screen(pos);
+    ///
+    /// assert!(screen_pos.x != 0.0);
+    /// assert!(screen_pos.y != 0.0);
+    /// assert!(screen_pos.z != 0.0);
+    /// ```
+    pub fn world_to_screen(&self, world_pos: IVec3) -> S::Vec3 {
+        let world_pos: IVec3 = self.layout.to_diamond(world_pos);
+
+        self.world_to_screen_f(S::Vec3::new(
+            S::from_i32(world_pos.x),
+            S::from_i32(world_pos.y),
+            S::from_i32(world_pos.z)
+        ))
+    }
+
+    /// Converts 3d grid positions to the screen position of their diamond center, ignoring the anchor.
+    ///
+    /// Like [`IsoProjection::world_to_screen`] the elevation is returned in z. With
+    /// [`Anchor::Center`] both methods return the same position, and the other
+    /// anchors are the center moved to the top or bottom vertex of the diamond.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec3 };
+    /// use spriso::{ Anchor, IsoProjection };
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let pos: IVec3 = IVec3::new(3, -2, 1);
+    /// let center: Vec3 = proj.world_to_screen_center(pos);
+    ///
+    /// assert_eq!(proj.world_to_screen(pos), center);
+    /// assert_eq!(proj.with_anchor(Anchor::Top).world_to_screen(pos), center - Vec3::new(0.0, 7.0, 0.0));
+    /// assert_eq!(proj.with_anchor(Anchor::Bottom).world_to_screen(pos), center + Vec3::new(0.0, 7.0, 0.0));
+    /// assert_eq!(proj.with_anchor(Anchor::Top).world_to_screen_center(pos), center);
+    /// ```
+    pub fn world_to_screen_center(&self, world_pos: IVec3) -> S::Vec3 {
+        let world_pos: IVec3 = self.layout.to_diamond(world_pos);
+
+        self.project_f(
+            S::Vec3::new(S::from_i32(world_pos.x), S::from_i32(world_pos.y), S::from_i32(world_pos.z)),
+            self.applied_origin()
+        )
+    }
+
+    /// Converts 3d grid positions to the 2D screen point their sprite is drawn at, with the elevation folded into y.
+    ///
+    /// This is the x/y of [`IsoProjection::world_to_screen`] moved up the screen by
+    /// the elevation in z, so elevated tiles are already positioned higher and the
+    /// result can be fed straight to a renderer. [`IsoProjection::screen_flat_to_world`]
+    /// is the inverse for a known z level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2, Vec3 };
+    /// use spriso::{ IsoProjection, YAxis };
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let pos: IVec3 = IVec3::new(3, 1, 2);
+    /// let screen_pos: Vec3 = proj.world_to_screen(pos);
+    ///
+    /// assert_eq!(proj.world_to_screen_flat(pos), Vec2::new(screen_pos.x, screen_pos.y - screen_pos.z));
+    /// assert_eq!(proj.with_y_axis(YAxis::Up).world_to_screen_flat(pos), Vec2::new(28.0, 0.0));
+    /// ```
+    pub fn world_to_screen_flat(&self, world_pos: IVec3) -> S::Vec2 {
+        self.drawn_screen_pos(self.world_to_screen(world_pos))
+    }
+
+    /// Returns the distance in screen pixels between where two tiles are drawn.
+    ///
+    /// This is the Euclidean distance between their
+    /// [`IsoProjection::world_to_screen_flat`] points, so elevation and zoom count.
+    /// For step counts on the grid use [`grid::manhattan_distance`] or [`grid::chebyshev_distance`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::IVec3;
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<16, 24>();
+    ///
+    /// assert_eq!(proj.screen_distance(IVec3::ZERO, IVec3::new(1, 1, 0)), 24.0);
+    /// assert_eq!(proj.screen_distance(IVec3::ZERO, IVec3::new(1, 1, 1)), 0.0);
+    /// assert_eq!(proj.screen_distance(IVec3::ZERO, IVec3::new(1, -1, 0)), 32.0);
+    /// ```
+    pub fn screen_distance(&self, a: IVec3, b: IVec3) -> S {
+        (self.world_to_screen_flat(a) - self.world_to_screen_flat(b)).length()
+    }
+
+    /// Converts a 2D screen point with the elevation folded into y to the tile it hits on the `world_z` level.
+    ///
+    /// The inverse of [`IsoProjection::world_to_screen_flat`]: the anchor offset is
+    /// removed and the point is hit tested with [`IsoProjection::tile_at_screen`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::IVec3;
+    /// use spriso::{ Anchor, IsoProjection };
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>().with_anchor(Anchor::Bottom);
+    /// let pos: IVec3 = IVec3::new(-4, 7, 3);
+    ///
+    /// assert_eq!(proj.screen_flat_to_world(proj.world_to_screen_flat(pos), pos.z), pos);
+    /// ```
+    pub fn screen_flat_to_world(&self, screen: S::Vec2, world_z: i32) -> IVec3 {
+        self.tile_at_screen(screen - (self.screen_offset() - self.applied_origin()), world_z)
+    }
+
+    /// Snaps a screen point to whole pixels, to draw sprites without seams between tiles.
+    ///
+    /// Each component is rounded with `floor(x + 0.5)`, so half pixels always round
+    /// towards +x and +y, on either side of zero. Tiles sharing an edge share the
+    /// same unsnapped corner, so snapping both gives them the same pixel. Snapping
+    /// moves a point by at most half a pixel per axis, while the drawn diamond reaches
+    /// `half_tw` pixels sideways and `half_th / 2` pixels up and down from its center.
+    /// So feeding a snapped point of [`IsoProjection::world_to_screen_flat`] back to
+    /// [`IsoProjection::screen_flat_to_world`] still finds its tile as long as
+    /// `0.5 / half_tw + 1.0 / half_th < 1` after zoom, e.g. for `half_tw >= 2` and
+    /// `half_th >= 4`. Smaller tiles can snap onto a neighbour.
+    ///
+    /// The method doesn't depend on the projection's configuration. It takes `&self`
+    /// so it's called like the conversions whose results it snaps, with the scalar
+    /// type taken from the projection.
+    ///
+    /// A zoom that keeps the half-tile width, height and z scale whole numbers of
+    /// pixels moves every tile by the same snap, so the grid stays exactly regular.
+    /// With any other zoom the snapped step between neighbours varies by a pixel,
+    /// so sprites drawn at a fixed size can overlap or gap by that pixel: scale the
+    /// sprites to reach the next snapped position, or pick the zoom accordingly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let mut proj: IsoProjection = IsoProjection::new::<14, 14>().with_origin(Vec2::new(100.5, 50.25));
+    /// proj.set_zoom(2.0);
+    ///
+    /// assert_eq!(proj.snap_screen(Vec2::new(0.5, -0.5)), Vec2::new(1.0, 0.0));
+    ///
+    /// let a: Vec2 = proj.snap_screen(proj.world_to_screen_flat(IVec3::new(2, 3, 0)));
+    /// let b: Vec2 = proj.snap_screen(proj.world_to_screen_flat(IVec3::new(3, 3, 0)));
+    /// assert_eq!(b - a, Vec2::new(28.0, 14.0));
+    ///
+    /// for pos in [IVec3::new(2, 3, 0), IVec3::new(-5, 8, 2), IVec3::new(0, 0, -1)] {
+    ///     assert_eq!(proj.screen_flat_to_world(proj.snap_screen(proj.world_to_screen_flat(pos)), pos.z), pos);
+    /// }
+    ///
+    /// // A diamond only one pixel high is too small to survive the snap.
+    /// let tiny: IsoProjection = IsoProjection::from_dims(1.0, 1.0).with_origin(Vec2::new(0.5, 0.5));
+    /// assert_ne!(tiny.screen_flat_to_world(tiny.snap_screen(tiny.world_to_screen_flat(IVec3::ZERO)), 0), IVec3::ZERO);
+    /// ```
+    pub fn snap_screen(&self, screen: S::Vec2) -> S::Vec2 {
+        (screen + S::Vec2::new(S::HALF, S::HALF)).floor()
+    }
+
+    /// Converts 3d grid positions to the point their sprite is drawn at under a 2D UI camera.
+    ///
+    /// The [`IsoProjection::world_to_screen_flat`] point is moved through `camera`,
+    /// the transform from this projection's screen space to UI space with its own
+    /// translation, scale and rotation. [`IsoProjection::screen_to_world_with_camera`]
+    /// is the inverse for a known z level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ Affine2, IVec3, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let camera: Affine2 = Affine2::from_scale_angle_translation(Vec2::splat(2.0), 0.0, Vec2::new(400.0, 300.0));
+    ///
+    /// assert_eq!(proj.world_to_ui(IVec3::new(1, 0, 0), camera), Vec2::new(428.0, 314.0));
+    /// ```
+    pub fn world_to_ui(&self, world_pos: IVec3, camera: S::Affine2) -> S::Vec2 {
+        camera.transform_point2(self.world_to_screen_flat(world_pos))
+    }
+
+    /// Converts a UI point under a 2D camera to the grid x/y of the tile it hits on the `world_z` level.
+    ///
+    /// The inverse of `camera` moves the point back to this projection's screen
+    /// space, where it's converted with [`IsoProjection::screen_flat_to_world`], so the
+    /// elevation of the level is accounted for. This is the inverse of [`IsoProjection::world_to_ui`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ Affine2, IVec3, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>().with_origin(Vec2::new(12.0, -30.0));
+    /// let camera: Affine2 = Affine2::from_scale_angle_translation(Vec2::splat(1.5), 0.3, Vec2::new(400.0, 300.0));
+    ///
+    /// for pos in [IVec3::new(0, 0, 0), IVec3::new(5, -3, 2), IVec3::new(-7, 4, -1)] {
+    ///     let ui_point: Vec2 = proj.world_to_ui(pos, camera);
+    ///
+    ///     assert_eq!(proj.screen_to_world_with_camera(ui_point, camera, pos.z), pos.truncate());
+    ///     assert_eq!(proj.screen_to_world_with_camera(ui_point + Vec2::new(3.0, -2.0), camera, pos.z), pos.truncate());
+    /// }
+    /// ```
+    pub fn screen_to_world_with_camera(&self, ui_point: S::Vec2, camera: S::Affine2, world_z: i32) -> IVec2 {
+        self.screen_flat_to_world(camera.inverse().transform_point2(ui_point), world_z).truncate()
+    }
+
+    /// Converts fractional 3d grid positions to their corresponding screen position.
+    ///
+    /// Applies the same projection as [`IsoProjection::world_to_screen`] without
+    /// snapping to a tile, so smoothly moving units can be placed between tiles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// let a: Vec3 = proj.world_to_screen(IVec3::new(2, 5, 1));
+    /// let b: Vec3 = proj.world_to_screen(IVec3::new(3, 5, 1));
+    /// let halfway: Vec3 = proj.world_to_screen_f(Vec3::new(2.5, 5.0, 1.0));
+    ///
+    /// assert_eq!(halfway, (a + b) * 0.5);
+    /// ```
+    pub fn world_to_screen_f(&self, world_pos: S::Vec3) -> S::Vec3 {
+        self.project_f(world_pos, self.screen_offset())
+    }
+
+    /// Converts screen positions to their corresponding 3d grid positions.
+    ///
+    /// Each component is rounded to the nearest tile with `floor(x + 0.5)`, so every
+    /// tile owns the half-open cell `[n - 0.5, n + 0.5)` and half-way cases always go
+    /// to the greater tile, the same rule as [`IsoProjection::tile_at_screen`].
+    /// Projecting an integer position and unprojecting it returns the same position
+    /// within the scalar's precision: up to roughly 2^21 tiles from the origin for
+    /// `f32` and 2^50 for `f64`, see [`IsoProjection::world_to_screen_i64`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// let screen_pos: Vec3 = proj.world_to_screen(IVec3::new(10, 20, 30)) + Vec3::new(3.0, -2.0, 5.0);
+    ///
+    /// assert_eq!(proj.screen_to_world(screen_pos), IVec3::new(10, 20, 30));
+    /// assert_eq!(proj.screen_to_world(Vec3::new(7.0, 3.5, -7.0)), IVec3::new(1, 0, 0));
+    /// ```
+    ///
+    /// The round trip holds across a large grid in both precisions and layouts.
+    ///
+    /// ```
+    /// use glam::{ DVec3, IVec3, Vec3 };
+    /// use spriso::{ IsoProjection, Layout };
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let staggered: IsoProjection = proj.with_layout(Layout::Staggered);
+    /// let proj_f64: IsoProjection<f64> = IsoProjection::from_dims(14.0, 7.0);
+    ///
+    /// for z in (-1000..=1000).step_by(40) {
+    ///     for y in (-1000..=1000).step_by(20) {
+    ///         for x in (-1000..=1000).step_by(20) {
+    ///             let pos: IVec3 = IVec3::new(x, y, z);
+    ///
+    ///             assert_eq!(proj.screen_to_world(proj.world_to_screen(pos)), pos);
+    ///             assert_eq!(staggered.screen_to_world(staggered.world_to_screen(pos)), pos);
+    ///             assert_eq!(proj_f64.screen_to_world(proj_f64.world_to_screen(pos)), pos);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Negative positions are snapped by the same half-open rule as positive ones:
+    /// every tile `n` owns the translation-invariant cell `[n - 0.5, n + 0.5)`, also
+    /// far from the origin, instead of rounding towards zero. Points that land exactly
+    /// on a cell edge after float math may fall to either side of it, but every integer
+    /// position within the scalar's precision round-trips.
+    ///
+    /// ```
+    /// use glam::{ DVec3, IVec3, Vec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let offsets: [Vec3; 4] = [Vec3::splat(0.49), Vec3::splat(-0.49), Vec3::new(0.49, -0.49, 0.0), Vec3::new(-0.49, 0.49, 0.49)];
+    ///
+    /// for pos in [IVec3::new(-1, -1, -1), IVec3::new(-2, 3, -7), IVec3::new(-999, -1000, -1), IVec3::splat(-10_000)] {
+    ///     for tile in [pos, -pos] {
+    ///         assert_eq!(proj.screen_to_world(proj.world_to_screen(tile)), tile);
+    ///
+    ///         for offset in offsets {
+    ///             assert_eq!(proj.screen_to_world(proj.world_to_screen_f(tile.as_vec3() + offset)), tile);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let proj_f64: IsoProjection<f64> = IsoProjection::from_dims(14.0, 14.0);
+    /// let tile: IVec3 = IVec3::splat(-10_000_000);
+    ///
+    /// assert_eq!(proj_f64.screen_to_world(proj_f64.world_to_screen_f(tile.as_dvec3() + DVec3::splat(-0.49))), tile);
+    /// assert_eq!(proj_f64.screen_to_world(proj_f64.world_to_screen_f(tile.as_dvec3() + DVec3::splat(0.49))), tile);
+    /// ```
+    pub fn screen_to_world(&self, screen_pos: S::Vec3) -> IVec3 {
+        self.layout.from_diamond(Self::nearest_tile(self.screen_to_world_f(screen_pos)))
+    }
+
+    /// Converts screen positions to their corresponding 3d grid positions with a chosen [`RoundingMode`].
+    ///
+    /// [`RoundingMode::Nearest`] matches [`IsoProjection::screen_to_world`].
+    /// [`RoundingMode::Floor`] rounds every diamond grid component down instead, so
+    /// the result is the grid cell between tile centers that the point falls into.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec3 };
+    /// use spriso::{ IsoProjection, RoundingMode };
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// // A quarter tile towards -x and -y from the origin.
+    /// let screen_pos: Vec3 = proj.world_to_screen_f(Vec3::new(-0.25, -0.25, 0.0));
+    ///
+    /// assert_eq!(proj.screen_to_world_with_rounding(screen_pos, RoundingMode::Nearest), IVec3::new(0, 0, 0));
+    /// assert_eq!(proj.screen_to_world_with_rounding(screen_pos, RoundingMode::Floor), IVec3::new(-1, -1, 0));
+    /// ```
+    pub fn screen_to_world_with_rounding(&self, screen_pos: S::Vec3, mode: RoundingMode) -> IVec3 {
+        let world_pos: S::Vec3 = self.screen_to_world_f(screen_pos);
+
+        self.layout.from_diamond(match mode {
+            RoundingMode::Nearest => Self::nearest_tile(world_pos),
+            RoundingMode::Floor => world_pos.floor().as_ivec3(),
+        })
+    }
+
+    /// Converts screen positions to their grid positions like [`IsoProjection::screen_to_world`], or `None` outside a map of `map_size` tiles.
+    ///
+    /// The map holds the tiles from zero up to but excluding `map_size` in each
+    /// axis, in the projection's [`Layout`], so the result can index map storage directly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a `map_size` component isn't positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::IVec3;
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let map_size: IVec3 = IVec3::new(8, 6, 2);
+    ///
+    /// assert_eq!(proj.screen_to_world_checked(proj.world_to_screen(IVec3::new(7, 5, 1)), map_size), Some(IVec3::new(7, 5, 1)));
+    ///
+    /// // Clicks just outside each edge.
+    /// for outside in [IVec3::new(-1, 2, 0), IVec3::new(8, 2, 0), IVec3::new(3, -1, 0), IVec3::new(3, 6, 0), IVec3::new(3, 2, -1), IVec3::new(3, 2, 2)] {
+    ///     assert_eq!(proj.screen_to_world_checked(proj.world_to_screen(outside), map_size), None);
+    /// }
+    /// ```
+    pub fn screen_to_world_checked(&self, screen_pos: S::Vec3, map_size: IVec3) -> Option<IVec3> {
+        assert!(map_size.cmpgt(IVec3::ZERO).all(), "map size must be positive, got {map_size}");
+
+        let world_pos: IVec3 = self.screen_to_world(screen_pos);
+
+        (world_pos.cmpge(IVec3::ZERO).all() && world_pos.cmplt(map_size).all()).then_some(world_pos)
+    }
+
+    /// Converts screen positions to their grid positions like [`IsoProjection::screen_to_world`], clamped into a map of `map_size` tiles.
+    ///
+    /// Each axis is clamped into `0..map_size` separately, so a click off the map
+    /// picks the nearest tile along its edge rather than an out of range position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a `map_size` component isn't positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::IVec3;
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let map_size: IVec3 = IVec3::new(8, 6, 2);
+    ///
+    /// // Clicks just outside each edge land on the edge tile.
+    /// let clamped = |pos: IVec3| proj.screen_to_world_clamped(proj.world_to_screen(pos), map_size);
+    ///
+    /// assert_eq!(clamped(IVec3::new(-1, 2, 0)), IVec3::new(0, 2, 0));
+    /// assert_eq!(clamped(IVec3::new(8, 2, 0)), IVec3::new(7, 2, 0));
+    /// assert_eq!(clamped(IVec3::new(3, -1, 0)), IVec3::new(3, 0, 0));
+    /// assert_eq!(clamped(IVec3::new(3, 6, 0)), IVec3::new(3, 5, 0));
+    /// assert_eq!(clamped(IVec3::new(3, 2, -1)), IVec3::new(3, 2, 0));
+    /// assert_eq!(clamped(IVec3::new(3, 2, 2)), IVec3::new(3, 2, 1));
+    /// assert_eq!(clamped(IVec3::new(-40, 90, 7)), IVec3::new(0, 5, 1));
+    /// ```
+    pub fn screen_to_world_clamped(&self, screen_pos: S::Vec3, map_size: IVec3) -> IVec3 {
+        assert!(map_size.cmpgt(IVec3::ZERO).all(), "map size must be positive, got {map_size}");
+
+        self.screen_to_world(screen_pos).clamp(IVec3::ZERO, map_size - IVec3::ONE)
+    }
+
+    /// Converts 64-bit grid positions to their corresponding screen position, for maps larger than `i32` can address.
+    ///
+    /// Matches [`IsoProjection::world_to_screen`] for positions that fit in an `i32`.
+    /// The position is converted to the projection's scalar first, so precision
+    /// rather than the integer type limits how far out tiles stay distinct: `f32`
+    /// projections start to return neighbouring tiles from [`IsoProjection::screen_to_world_i64`]
+    /// beyond roughly 2^21 (two million) tiles from the origin, `f64` ones beyond roughly 2^50.
+    /// Use an [`IsoProjectionF64`] for very large maps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ DVec3, I64Vec3, IVec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection<f64> = IsoProjection::from_dims(14.0, 14.0);
+    /// let pos: I64Vec3 = I64Vec3::new(5_000_000_000, -7_000_000_000, 12);
+    ///
+    /// assert_eq!(proj.screen_to_world_i64(proj.world_to_screen_i64(pos)), pos);
+    /// assert_eq!(proj.world_to_screen_i64(I64Vec3::new(3, -2, 1)), proj.world_to_screen(IVec3::new(3, -2, 1)));
+    /// ```
+    pub fn world_to_screen_i64(&self, world_pos: I64Vec3) -> S::Vec3 {
+        let world_pos: I64Vec3 = self.layout.to_diamond_i64(world_pos);
+
+        self.world_to_screen_f(S::Vec3::new(
+            S::from_i64(world_pos.x),
+            S::from_i64(world_pos.y),
+            S::from_i64(world_pos.z)
+        ))
+    }
+
+    /// Converts screen positions to their corresponding 64-bit grid positions.
+    ///
+    /// Rounds like [`IsoProjection::screen_to_world`], see [`IsoProjection::world_to_screen_i64`]
+    /// for the range in which tiles stay distinct.
+    pub fn screen_to_world_i64(&self, screen_pos: S::Vec3) -> I64Vec3 {
+        let world_pos: S::Vec3 = self.screen_to_world_f(screen_pos) + S::Vec3::new(S::HALF, S::HALF, S::HALF);
+
+        self.layout.to_layout_i64(world_pos.floor().as_i64vec3())
+    }
+
+    /// Converts screen positions to their corresponding fractional 3d grid positions.
+    ///
+    /// Unlike [`IsoProjection::screen_to_world`] the result isn't rounded, so the
+    /// position within a tile is kept for smooth dragging.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::Vec3;
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// let pos: Vec3 = Vec3::new(1.25, -3.5, 0.75);
+    ///
+    /// assert!(proj.screen_to_world_f(proj.world_to_screen_f(pos)).abs_diff_eq(pos, 1e-5));
+    /// ```
+    pub fn screen_to_world_f(&self, screen_pos: S::Vec3) -> S::Vec3 {
+        self.unproject_f(screen_pos, self.screen_offset())
+    }
+
+    /// Converts a grid x/y position on the z = 0 level to its screen x/y.
+    ///
+    /// Matches the x/y of [`IsoProjection::world_to_screen`] for z = 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec2, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let pos: IVec2 = IVec2::new(4, -7);
+    ///
+    /// assert_eq!(proj.world_to_screen_2d(pos), proj.world_to_screen(pos.extend(0)).truncate());
+    /// assert_eq!(proj.world_to_screen_2d(IVec2::new(1, 0)), Vec2::new(14.0, 7.0));
+    /// ```
+    pub fn world_to_screen_2d(&self, world_pos: IVec2) -> S::Vec2 {
+        let screen_pos: S::Vec3 = self.world_to_screen(world_pos.extend(0));
+
+        S::Vec2::new(screen_pos.x(), screen_pos.y())
+    }
+
+    /// Converts a screen x/y to the grid x/y position it hits on the z = 0 level.
+    ///
+    /// Matches the x/y of [`IsoProjection::screen_to_world`] for a screen z of zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec2, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let pos: IVec2 = IVec2::new(4, -7);
+    ///
+    /// assert_eq!(proj.screen_to_world_2d(proj.world_to_screen_2d(pos)), pos);
+    /// assert_eq!(proj.screen_to_world_2d(Vec2::new(3.0, 9.0)), proj.screen_to_world(Vec2::new(3.0, 9.0).extend(0.0)).truncate());
+    /// ```
+    pub fn screen_to_world_2d(&self, screen: S::Vec2) -> IVec2 {
+        self.screen_to_world(S::Vec3::new(screen.x(), screen.y(), S::ZERO)).truncate()
+    }
+
+    /// Converts a screen x/y displacement, such as a drag, to the world x/y displacement it covers.
+    ///
+    /// Only the inverse matrix and zoom are applied, with no origin, anchor or
+    /// rounding, so panning by summed deltas doesn't drift the way unprojecting
+    /// two absolute points and subtracting does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec2, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let mut proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// proj.set_origin(Vec2::new(320.0, 240.0));
+    /// proj.set_zoom(2.0);
+    ///
+    /// let delta: Vec2 = proj.world_to_screen_2d(IVec2::new(3, -1)) - proj.world_to_screen_2d(IVec2::ZERO);
+    ///
+    /// assert!(proj.screen_delta_to_world(delta).abs_diff_eq(Vec2::new(3.0, -1.0), 1e-5));
+    /// assert_eq!(proj.screen_delta_to_world(Vec2::ZERO), Vec2::ZERO);
+    /// ```
+    pub fn screen_delta_to_world(&self, delta: S::Vec2) -> S::Vec2 {
+        self.inv_iso_matrix_2d * delta * self.inv_zoom
+    }
+
+    /// Converts a drawn screen point to the grid position it hits on the `world_z` level.
+    ///
+    /// A tile at world z is drawn `z * z_scale` pixels higher on screen than its
+    /// [`IsoProjection::world_to_screen`] x/y, so clicking it would otherwise return
+    /// the wrong x/y. This removes that elevation offset from screen y before unprojecting.
+    /// Like [`IsoProjection::screen_to_world_on_z`] the point refers to the projection's
+    /// [`Anchor`], so this is the x/y of [`IsoProjection::screen_flat_to_world`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec2, IVec3, Vec2, Vec3 };
+    /// use spriso::{ Anchor, IsoProjection };
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// let screen_pos: Vec3 = proj.world_to_screen(IVec3::new(3, 4, 2));
+    /// let drawn_pos: Vec2 = Vec2::new(screen_pos.x, screen_pos.y - screen_pos.z);
+    ///
+    /// assert_eq!(proj.screen_to_world_on_plane(drawn_pos, 2), IVec2::new(3, 4));
+    ///
+    /// // With a bottom anchor the screen position is the diamond's bottom vertex.
+    /// let bottom: IsoProjection = proj.with_anchor(Anchor::Bottom);
+    /// let screen_pos: Vec3 = bottom.world_to_screen(IVec3::ZERO);
+    ///
+    /// assert_eq!(bottom.screen_to_world_on_plane(Vec2::new(screen_pos.x, screen_pos.y), 0), IVec2::ZERO);
+    /// assert_eq!(bottom.screen_to_world_on_z(Vec2::new(screen_pos.x, screen_pos.y), 0.0), Vec2::ZERO);
+    /// ```
+    pub fn screen_to_world_on_plane(&self, screen_xy: S::Vec2, world_z: i32) -> IVec2 {
+        self.screen_flat_to_world(screen_xy, world_z).truncate()
+    }
+
+    /// Converts a drawn screen point to the fractional grid x/y it hits on a fractional `world_z` plane.
+    ///
+    /// Surfaces between integer levels, such as ramps or water, are picked by
+    /// choosing the z of the surface under the cursor. The elevation of that
+    /// plane is removed from screen y before unprojecting, and like
+    /// [`IsoProjection::screen_to_world_f`] the point refers to the projection's
+    /// [`Anchor`] and the result is in diamond grid coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ Vec2, Vec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// let pos: Vec3 = Vec3::new(2.25, -1.5, 0.4);
+    /// let screen_pos: Vec3 = proj.world_to_screen_f(pos);
+    /// let drawn_pos: Vec2 = Vec2::new(screen_pos.x, screen_pos.y - screen_pos.z);
+    ///
+    /// assert!(proj.screen_to_world_on_z(drawn_pos, 0.4).abs_diff_eq(pos.truncate(), 1e-5));
+    /// ```
+    pub fn screen_to_world_on_z(&self, screen: S::Vec2, world_z: S) -> S::Vec2 {
+        let elevation: S = world_z * self.z_scale * self.zoom;
+        let ground_y: S = screen.y() + self.y_axis.orient(elevation);
+        let world_pos: S::Vec3 = self.unproject_f(S::Vec3::new(screen.x(), ground_y, elevation), self.screen_offset());
+
+        S::Vec2::new(world_pos.x(), world_pos.y())
+    }
+
+    /// Returns the tile on the `world_z` level whose drawn diamond contains a screen point.
+    ///
+    /// Each tile's diamond is the image of the unit square around its grid position,
+    /// so this is an exact containment test rather than a nearest center search.
+    /// Points on an edge shared by two diamonds go to the tile with the greater
+    /// diamond grid coordinate across that edge, and a vertex shared by four diamonds goes
+    /// to the tile greater in both x and y. Every screen point hits exactly one tile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// assert_eq!(proj.tile_at_screen(Vec2::new(0.0, 0.0), 0), IVec3::new(0, 0, 0));
+    /// assert_eq!(proj.tile_at_screen(Vec2::new(13.0, 0.0), 0), IVec3::new(0, 0, 0));
+    ///
+    /// // The edge between (0, 0) and (1, 0), and the bottom vertex of (0, 0).
+    /// assert_eq!(proj.tile_at_screen(Vec2::new(7.0, 3.5), 0), IVec3::new(1, 0, 0));
+    /// assert_eq!(proj.tile_at_screen(Vec2::new(0.0, 7.0), 0), IVec3::new(1, 1, 0));
+    ///
+    /// // Elevated tiles are hit where they're drawn.
+    /// assert_eq!(proj.tile_at_screen(Vec2::new(0.0, -14.0), 1), IVec3::new(0, 0, 1));
+    /// ```
+    pub fn tile_at_screen(&self, screen: S::Vec2, world_z: i32) -> IVec3 {
+        let tile: IVec2 = (self.drawn_to_world_f(screen, world_z) + S::Vec2::new(S::HALF, S::HALF)).floor().as_ivec2();
+
+        self.layout.from_diamond(tile.extend(world_z))
+    }
+
+    /// Returns every tile a screen point hits, one per z level in `z_levels`.
+    ///
+    /// Each level is hit tested with [`IsoProjection::tile_at_screen`], so the
+    /// elevation offset of that level is accounted for. Tiles are yielded top-most
+    /// first, from the highest z down, so the first occupied tile is the one drawn
+    /// in front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// let hits: Vec<IVec3> = proj.screen_to_world_ray(Vec2::new(0.0, 0.0), 0..3).collect();
+    ///
+    /// assert_eq!(hits, [IVec3::new(2, 2, 2), IVec3::new(1, 1, 1), IVec3::new(0, 0, 0)]);
+    /// ```
+    pub fn screen_to_world_ray(&self, screen: S::Vec2, z_levels: Range<i32>) -> impl Iterator<Item = IVec3> {
+        z_levels.rev().map(move |world_z| self.tile_at_screen(screen, world_z))
+    }
+
+    /// Returns the top-most tile under a screen point for which `occupied` returns `true`.
+    ///
+    /// The levels in `z_range` are scanned from the highest z down like
+    /// [`IsoProjection::screen_to_world_ray`], stopping at the first occupied tile,
+    /// so this is the tile drawn in front at that point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let occupied = |pos: IVec3| pos.z <= 1;
+    ///
+    /// assert_eq!(proj.pick_topmost(Vec2::new(0.0, 0.0), 0..4, occupied), Some(IVec3::new(1, 1, 1)));
+    /// assert_eq!(proj.pick_topmost(Vec2::new(0.0, 0.0), 2..4, occupied), None);
+    /// ```
+    pub fn pick_topmost<F: Fn(IVec3) -> bool>(&self, screen: S::Vec2, z_range: Range<i32>, occupied: F) -> Option<IVec3> {
+        self.screen_to_world_ray(screen, z_range).find(|world_pos| occupied(*world_pos))
+    }
+
+    /// Snaps a screen point to the drawn center of the tile it hits on the `world_z` level.
+    ///
+    /// This is [`IsoProjection::tile_at_screen`] followed by projecting the tile's
+    /// diamond center back to the screen with its elevation folded into y, so the result
+    /// lies in the same drawn space as `screen` and ignores the anchor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// assert_eq!(proj.snap_to_tile_center(Vec2::new(12.0, 5.0), 0), Vec2::new(14.0, 7.0));
+    /// assert_eq!(proj.snap_to_tile_center(Vec2::new(12.0, -9.0), 1), Vec2::new(14.0, -7.0));
+    /// ```
+    pub fn snap_to_tile_center(&self, screen: S::Vec2, world_z: i32) -> S::Vec2 {
+        self.drawn_position(self.tile_at_screen(screen, world_z))
+    }
+
+    /// Returns the tile a screen point hits on the `world_z` level and where inside it the point is.
+    ///
+    /// The tile is the one picked by [`IsoProjection::tile_at_screen`]. The offset is
+    /// the fractional diamond grid position of the point, as
+    /// [`IsoProjection::screen_to_world_f`] returns it with the default anchor, minus
+    /// the tile and shifted into `[0, 1)` per axis, so `(0, 0)` is the top vertex,
+    /// `(1, 0)` the right, `(1, 1)` the bottom and `(0, 1)` the left vertex in the
+    /// unrotated view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec2, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// let (tile, offset): (IVec2, Vec2) = proj.pick_with_offset(Vec2::new(14.0, 7.0), 0);
+    /// assert_eq!((tile, offset), (IVec2::new(1, 0), Vec2::new(0.5, 0.5)));
+    ///
+    /// // Left of the tile center, towards its left vertex.
+    /// let (tile, offset): (IVec2, Vec2) = proj.pick_with_offset(Vec2::new(-7.0, 0.0), 0);
+    /// assert_eq!(tile, IVec2::new(0, 0));
+    /// assert!(offset.x < 0.5 && offset.y > 0.5);
+    /// ```
+    pub fn pick_with_offset(&self, screen: S::Vec2, world_z: i32) -> (IVec2, S::Vec2) {
+        let shifted: S::Vec2 = self.drawn_to_world_f(screen, world_z) + S::Vec2::new(S::HALF, S::HALF);
+        let cell: S::Vec2 = shifted.floor();
+        let tile: IVec3 = self.layout.from_diamond(cell.as_ivec2().extend(world_z));
+
+        (tile.truncate(), shifted - cell)
+    }
+
+    /// Returns a painter's algorithm sort key for a grid position.
+    ///
+    /// The key grows along the isometric view direction: first with `x + y` of the
+    /// rotated grid, which matches the screen y order of tiles on the same z level,
+    /// and then with z, so higher tiles draw in front. Sorting tiles by ascending key draws them back to front.
+    ///
+    /// Tiles with equal keys lie on the same view plane and don't overlap on screen,
+    /// so any order between them is correct. Sort with a stable sort to keep their
+    /// original order deterministic. These guarantees hold for the tile sizes of the
+    /// other constructors and unsheared bases only: with a sheared basis given to
+    /// [`IsoProjection::from_basis`] the key no longer follows screen y and tiles with
+    /// equal keys can overlap, so sort by [`IsoProjection::screen_depth_key`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::IVec3;
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// let mut tiles: Vec<IVec3> = vec![IVec3::new(1, 1, 0), IVec3::new(0, 0, 0), IVec3::new(1, 1, 1)];
+    /// tiles.sort_by(|a, b| proj.depth_key(*a).total_cmp(&proj.depth_key(*b)));
+    ///
+    /// assert_eq!(tiles, [IVec3::new(0, 0, 0), IVec3::new(1, 1, 0), IVec3::new(1, 1, 1)]);
+    /// ```
+    pub fn depth_key(&self, world_pos: IVec3) -> S {
+        let world_pos: IVec3 = self.layout.to_diamond(world_pos);
+
+        self.depth_key_f(S::Vec3::new(
+            S::from_i32(world_pos.x),
+            S::from_i32(world_pos.y),
+            S::from_i32(world_pos.z)
+        ))
+    }
+
+    /// Converts a grid position to its screen position with z replaced by a depth in `0..=1`, for a GPU depth buffer.
+    ///
+    /// The x/y are those of [`IsoProjection::world_to_screen`]. The depth is the
+    /// tile's [`IsoProjection::depth_key`] mapped over the map of `map_size` tiles on
+    /// the `z_range` levels, from 1 at its back corner to 0 at its front corner, so
+    /// tiles in front get a smaller depth and a less-than depth test draws them over
+    /// the tiles they overlap in any order, with no sorting on the CPU. Tiles with
+    /// equal keys share a depth, which is fine as they don't overlap. A z outside
+    /// `z_range` is clamped to its nearest end, as are keys of tiles off the map, and
+    /// a map of a single tile maps it to 0. Keep the map below 2^24 depth keys in
+    /// `f32` so neighbouring keys stay distinct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `z_range` is empty or a `map_size` component isn't positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec2, IVec3, Vec2, Vec3 };
+    /// use spriso::{ Anchor, IsoProjection, Layout, Orientation };
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>().with_anchor(Anchor::Bottom);
+    /// let map_size: IVec2 = IVec2::new(8, 8);
+    ///
+    /// assert_eq!(proj.world_to_screen_ndc_depth(IVec3::ZERO, 0..2, map_size).z, 1.0);
+    /// assert_eq!(proj.world_to_screen_ndc_depth(IVec3::new(7, 7, 1), 0..2, map_size).z, 0.0);
+    /// assert_eq!(proj.world_to_screen_ndc_depth(IVec3::new(7, 7, 9), 0..2, map_size).z, 0.0);
+    /// assert_eq!(proj.world_to_screen_ndc_depth(IVec3::new(1, 0, 0), 0..2, map_size).truncate(), proj.world_to_screen(IVec3::new(1, 0, 0)).truncate());
+    ///
+    /// // Block sprites of the tile in front overlap the tile behind on the same level.
+    /// let (back, front): (IVec3, IVec3) = (IVec3::new(2, 2, 0), IVec3::new(3, 3, 0));
+    /// let sprite_size: Vec2 = Vec2::new(28.0, 28.0);
+    /// let (back_min, _): (Vec2, Vec2) = proj.tile_render_rect(back, sprite_size);
+    /// let (front_min, _): (Vec2, Vec2) = proj.tile_render_rect(front, sprite_size);
+    /// assert!(front_min.y - back_min.y < sprite_size.y);
+    ///
+    /// assert!(proj.world_to_screen_ndc_depth(front, 0..2, map_size).z < proj.world_to_screen_ndc_depth(back, 0..2, map_size).z);
+    /// assert!(proj.world_to_screen_ndc_depth(IVec3::new(2, 2, 1), 0..2, map_size).z < proj.world_to_screen_ndc_depth(back, 0..2, map_size).z);
+    ///
+    /// // The front-most staggered tile can sit one row in from the map's corner.
+    /// let staggered: IsoProjection = proj.with_layout(Layout::Staggered).with_orientation(Orientation::Deg90);
+    /// let ndc_depth = |pos: IVec3| staggered.world_to_screen_ndc_depth(pos, 0..1, IVec2::new(8, 7)).z;
+    /// assert_eq!(ndc_depth(IVec3::new(7, 5, 0)), 0.0);
+    /// assert!(ndc_depth(IVec3::new(7, 6, 0)) > 0.0);
+    /// ```
+    pub fn world_to_screen_ndc_depth(&self, world_pos: IVec3, z_range: Range<i32>, map_size: IVec2) -> S::Vec3 {
+        assert!(!z_range.is_empty(), "z range must not be empty, got {z_range:?}");
+        assert!(map_size.cmpgt(IVec2::ZERO).all(), "map size must be positive, got {map_size}");
+
+        let screen_pos: S::Vec3 = self.world_to_screen(world_pos);
+        let top: i32 = z_range.end - 1;
+        let (min, max): (S, S) = self.depth_key_bounds(IVec3::new(0, 0, z_range.start), (map_size - IVec2::ONE).extend(top));
+        let key: S = self.depth_key(world_pos.with_z(world_pos.z.clamp(z_range.start, top)));
+
+        let depth: S = if key >= max {
+            S::ZERO
+        } else if key <= min {
+            S::ONE
+        } else {
+            (max - key) / (max - min)
+        };
+
+        S::Vec3::new(screen_pos.x(), screen_pos.y(), depth)
+    }
+
+    /// Returns an integer painter's algorithm sort key for a tile on a map of `map_size` tiles.
+    ///
+    /// The key packs the depth of the rotated grid `x + y` as the major part, then z,
+    /// then the rotated grid x as a tie-breaker, so every tile on the map gets a
+    /// distinct key and sorting by it, e.g. with a radix sort, draws back to front.
+    /// Unlike [`IsoProjection::depth_key`] z never outweighs `x + y`, so each column
+    /// of stacked tiles is drawn bottom up before the columns in front of it.
+    ///
+    /// Positions must be in `0..map_size` of the projection's [`Layout`], which keeps
+    /// the packed key from overflowing. Maps up to 2^20 (1,048,576) tiles per side
+    /// and z in the `i16` range are supported.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a `map_size` component isn't in `1..=2^20`, `world_pos` x/y is outside
+    /// `0..map_size` or `world_pos.z` is outside the `i16` range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec2, IVec3 };
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let map_size: IVec2 = IVec2::new(64, 64);
+    ///
+    /// let mut tiles: Vec<IVec3> = vec![IVec3::new(1, 1, 0), IVec3::new(0, 0, 3), IVec3::new(0, 0, 1), IVec3::new(2, 0, 0)];
+    /// tiles.sort_by_key(|pos| proj.draw_order(*pos, map_size));
+    ///
+    /// assert_eq!(tiles, [IVec3::new(0, 0, 1), IVec3::new(0, 0, 3), IVec3::new(1, 1, 0), IVec3::new(2, 0, 0)]);
+    /// ```
+    pub fn draw_order(&self, world_pos: IVec3, map_size: IVec2) -> u64 {
+        const MAX_MAP_SIZE: i32 = 1 << 20;
+
+        assert!(
+            map_size.x >= 1 && map_size.x <= MAX_MAP_SIZE && map_size.y >= 1 && map_size.y <= MAX_MAP_SIZE,
+            "map size must be in 1..={MAX_MAP_SIZE} per side, got {map_size}"
+        );
+        assert!(
+            world_pos.truncate().cmpge(IVec2::ZERO).all() && world_pos.truncate().cmplt(map_size).all(),
+            "position must be inside the map of size {map_size}, got {world_pos}"
+        );
+        assert!(i16::try_from(world_pos.z).is_ok(), "z must be in the i16 range, got {}", world_pos.z);
+
+        let diamond_pos: IVec3 = self.layout.to_diamond(world_pos);
+        let view_pos: IVec2 = self.orientation.rotate(diamond_pos.truncate());
+
+        // Diamond coordinates of any layout and orientation stay within this bound
+        // of zero, so offsetting by it keeps every part of the key non-negative.
+        let bound: u64 = 2 * (map_size.x as u64 + map_size.y as u64);
+        let depth: u64 = (i64::from(view_pos.x) + i64::from(view_pos.y) + 2 * bound as i64) as u64;
+        let level: u64 = (i64::from(world_pos.z) - i64::from(i16::MIN)) as u64;
+        let tie: u64 = (i64::from(view_pos.x) + bound as i64) as u64;
+
+        (depth * (1 << 16) + level) * (2 * bound + 1) + tie
+    }
+
+    /// Buckets a tile's [`IsoProjection::depth_key`] into one of `layers` sort layers on a map of `map_size` tiles.
+    ///
+    /// The depth keys from the back-most to the front-most tile of the map are split into
+    /// `layers` equal spans, numbered from 0 at the back. A tile in a lower layer never
+    /// has a greater depth key than one in a higher layer, so drawing the layers in
+    /// order keeps the depth key order, only coarser. Tiles outside `0..map_size`
+    /// are clamped into the first or last layer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `layers` is zero or a `map_size` component isn't positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::IVec3;
+    /// use spriso::{ IsoProjection, Layout, Orientation };
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let map_size: IVec3 = IVec3::new(8, 8, 2);
+    ///
+    /// assert_eq!(proj.sort_layer(IVec3::ZERO, map_size, 4), 0);
+    /// assert_eq!(proj.sort_layer(IVec3::new(7, 7, 1), map_size, 4), 3);
+    ///
+    /// let mut tiles: Vec<IVec3> = Vec::new();
+    /// for z in 0..2 {
+    ///     for y in 0..8 {
+    ///         for x in 0..8 {
+    ///             tiles.push(IVec3::new(x, y, z));
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// for a in &tiles {
+    ///     for b in &tiles {
+    ///         if proj.depth_key(*a) < proj.depth_key(*b) {
+    ///             assert!(proj.sort_layer(*a, map_size, 4) <= proj.sort_layer(*b, map_size, 4));
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// // The front-most staggered tile can sit one row in from the map's corner.
+    /// let staggered: IsoProjection = proj.with_layout(Layout::Staggered).with_orientation(Orientation::Deg90);
+    /// assert_eq!(staggered.sort_layer(IVec3::new(7, 5, 0), IVec3::new(8, 7, 1), 16), 15);
+    /// assert_eq!(staggered.sort_layer(IVec3::new(7, 6, 0), IVec3::new(8, 7, 1), 16), 14);
+    /// ```
+    pub fn sort_layer(&self, world_pos: IVec3, map_size: IVec3, layers: u32) -> u32 {
+        assert!(layers > 0, "layer count must be positive");
+        assert!(map_size.cmpgt(IVec3::ZERO).all(), "map size must be positive, got {map_size}");
+
+        let (min, max): (S, S) = self.depth_key_bounds(IVec3::ZERO, map_size - IVec3::ONE);
+        let key: S = self.depth_key(world_pos);
+
+        if key <= min {
+            return 0;
+        }
+
+        // Depth keys of grid positions are whole numbers, so the map spans `max - min + 1` of them.
+        let layer: i32 = ((key - min) * S::from_i64(i64::from(layers)) / (max - min + S::ONE)).as_i32();
+
+        (layer as u32).min(layers - 1)
+    }
+
+    /// Returns a painter's algorithm sort key in screen pixels for a grid position.
+    ///
+    /// The key is the screen y of the tile's footprint on the z = 0 level, measured
+    /// downwards whatever the [`YAxis`], plus the tile's elevation, both after zoom.
+    /// Unlike [`IsoProjection::depth_key`], which weighs a z level the same as a step
+    /// in `x + y`, elevation counts for as many pixels as it lifts the tile, so the
+    /// tops of tall cliffs are keyed by how far up the screen they reach. A tile is
+    /// always keyed after every tile below, behind or to the back of it, so sorting
+    /// by ascending key draws stacked blocks back to front for any z scale, and the
+    /// keys can be compared with sprites keyed by the screen y of their feet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec2 };
+    /// use spriso::IsoProjection;
+    ///
+    /// // Blocks twice as tall as the diamond is high.
+    /// let proj: IsoProjection = IsoProjection::builder().half_tile(14.0, 14.0).z_scale(28.0).build().unwrap();
+    ///
+    /// // A four block cliff at the origin, surrounded by ground tiles.
+    /// let mut tiles: Vec<IVec3> = (0..4).map(|z| IVec3::new(0, 0, z)).collect();
+    /// for y in -3..=3 {
+    ///     for x in -3..=3 {
+    ///         if (x, y) != (0, 0) {
+    ///             tiles.push(IVec3::new(x, y, 0));
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// // The cliff top is drawn over the ground behind it, so it must come later.
+    /// let (top_min, _): (Vec2, Vec2) = proj.tile_screen_aabb(IVec3::new(0, 0, 3));
+    /// let (_, ground_max): (Vec2, Vec2) = proj.tile_screen_aabb(IVec3::new(-3, -3, 0));
+    /// assert!(top_min.y < ground_max.y);
+    /// assert!(proj.screen_depth_key(IVec3::new(0, 0, 3)) > proj.screen_depth_key(IVec3::new(-3, -3, 0)));
+    ///
+    /// // Every block comes after the blocks it can cover.
+    /// for back in &tiles {
+    ///     for front in &tiles {
+    ///         if back != front && back.cmple(*front).all() {
+    ///             assert!(proj.screen_depth_key(*back) < proj.screen_depth_key(*front));
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn screen_depth_key(&self, world_pos: IVec3) -> S {
+        let diamond_pos: IVec3 = self.layout.to_diamond(world_pos);
+        let view_pos: IVec2 = self.orientation.rotate(diamond_pos.truncate());
+
+        let ground_y: S = (self.basis * S::Vec2::new(S::from_i32(view_pos.x), S::from_i32(view_pos.y))).y() * self.zoom;
+        let elevation: S = S::from_i32(world_pos.z) * self.z_scale * self.zoom;
+
+        ground_y + elevation
+    }
+
+    /// Converts 3d grid float position to its corresponding screen position.
+    ///
+    /// Same as [`IsoProjection::world_to_screen_f`].
+    pub fn world_float_to_screen(&self, world_float_pos: S::Vec3) -> S::Vec3 {
+        self.world_to_screen_f(world_float_pos)
+    }
+
+    /// Checks that the projection and its z scale can be inverted and the tile size is positive.
+    ///
+    /// Every constructor and mutator changing the tile size goes through this, so a
+    /// projection that exists can always be serialized and loaded again.
+    pub(crate) fn validate(self) -> Result<Self, ProjectionError> {
+        let determinant: S = self.iso_matrix_2d.determinant();
+
+        if determinant == S::ZERO || !determinant.is_finite() || self.z_scale == S::ZERO || !self.z_scale.is_finite() {
+            return Err(ProjectionError::DegenerateMatrix);
+        }
+
+        if !(self.half_tw > S::ZERO && self.half_th > S::ZERO) {
+            return Err(ProjectionError::DegenerateBasis);
+        }
+
+        Ok(self)
+    }
+
+    /// Projects a fractional diamond position with its center moved by `offset` on screen.
+    pub(crate) fn project_f(&self, world_pos: S::Vec3, offset: S::Vec2) -> S::Vec3 {
+        let world_vec_2d: S::Vec2 = S::Vec2::new(world_pos.x(), world_pos.y());
+        let screen_vec_2d: S::Vec2 = self.iso_matrix_2d * world_vec_2d * self.zoom + offset;
+
+        S::Vec3::new(screen_vec_2d.x(), screen_vec_2d.y(), world_pos.z() * self.z_scale * self.zoom)
+    }
+
+    /// Unprojects a screen position whose tile centers are moved by `offset`, the inverse of [`IsoProjection::project_f`].
+    pub(crate) fn unproject_f(&self, screen_pos: S::Vec3, offset: S::Vec2) -> S::Vec3 {
+        let screen_vec_2d: S::Vec2 = (S::Vec2::new(screen_pos.x(), screen_pos.y()) - offset) * self.inv_zoom;
+        let world_vec_2d: S::Vec2 = self.inv_iso_matrix_2d * screen_vec_2d;
+
+        S::Vec3::new(world_vec_2d.x(), world_vec_2d.y(), screen_pos.z() * self.inv_z_scale * self.inv_zoom)
+    }
+
+    /// Returns the screen offset of the world origin, scaled by the parallax and moved by the layer offset.
+    pub(crate) fn applied_origin(&self) -> S::Vec2 {
+        self.origin * self.parallax + S::Vec2::new(S::ZERO, self.layer_offset)
+    }
+
+    /// Returns the screen offset of the world origin's anchor point, as used by the public conversions.
+    pub(crate) fn screen_offset(&self) -> S::Vec2 {
+        let (down, _): (S::Vec2, S::Vec2) = self.tile_half_diagonals();
+
+        match self.anchor {
+            Anchor::Top => self.applied_origin() - down,
+            Anchor::Center => self.applied_origin(),
+            Anchor::Bottom => self.applied_origin() + down,
+        }
+    }
+
+    /// Rounds a fractional diamond position to the tile whose half-open cell holds it.
+    pub(crate) fn nearest_tile(world_pos: S::Vec3) -> IVec3 {
+        (world_pos + S::Vec3::new(S::HALF, S::HALF, S::HALF)).floor().as_ivec3()
+    }
+
+    /// Returns the least and greatest [`IsoProjection::depth_key`] of the tiles in the inclusive box from `min` to `max`.
+    pub(crate) fn depth_key_bounds(&self, min: IVec3, max: IVec3) -> (S, S) {
+        // Depth keys are linear in diamond coordinates, so the box corners are the
+        // extremes. Staggered rows next to the edge rows are shifted by half a tile,
+        // so they're checked too, like in `region_screen_aabb`.
+        let rows: [i32; 4] = [min.y, (min.y + 1).min(max.y), (max.y - 1).max(min.y), max.y];
+        let (mut key_min, mut key_max): (S, S) = (self.depth_key(min), self.depth_key(min));
+
+        for z in [min.z, max.z] {
+            for y in rows {
+                for x in [min.x, max.x] {
+                    let key: S = self.depth_key(IVec3::new(x, y, z));
+
+                    if key < key_min {
+                        key_min = key;
+                    }
+                    if key > key_max {
+                        key_max = key;
+                    }
+                }
+            }
+        }
+
+        (key_min, key_max)
+    }
+
+    /// Returns the depth key of a fractional position in diamond coordinates.
+    pub(crate) fn depth_key_f(&self, world_pos: S::Vec3) -> S {
+        let (x_axis, y_axis): (S::Vec2, S::Vec2) = self.orientation.rotate_axes(
+            S::Vec2::new(S::ONE, S::ZERO),
+            S::Vec2::new(S::ZERO, S::ONE)
+        );
+        let view_pos: S::Vec2 = x_axis * world_pos.x() + y_axis * world_pos.y();
+
+        view_pos.x() + view_pos.y() + world_pos.z()
+    }
+
+    /// Returns the screen point a tile is drawn at, with its elevation folded into y.
+    pub(crate) fn drawn_position(&self, world_pos: IVec3) -> S::Vec2 {
+        self.drawn_screen_pos(self.world_to_screen_center(world_pos))
+    }
+
+    /// Unprojects a drawn screen point to the fractional diamond x/y it hits on the `world_z` level.
+    pub(crate) fn drawn_to_world_f(&self, screen: S::Vec2, world_z: i32) -> S::Vec2 {
+        let elevation: S = S::from_i32(world_z) * self.z_scale * self.zoom;
+        let ground_y: S = screen.y() + self.y_axis.orient(elevation);
+        let world_pos: S::Vec3 = self.unproject_f(S::Vec3::new(screen.x(), ground_y, elevation), self.applied_origin());
+
+        S::Vec2::new(world_pos.x(), world_pos.y())
+    }
+
+    /// Folds the elevation of a screen position into y, moving it up on screen.
+    pub(crate) fn drawn_screen_pos(&self, screen_pos: S::Vec3) -> S::Vec2 {
+        S::Vec2::new(screen_pos.x(), screen_pos.y() - self.y_axis.orient(screen_pos.z()))
+    }
+
+    /// Returns the offsets from a tile's center to its bottom and right diamond vertices.
+    ///
+    /// These come from the unrotated basis rather than the matrix columns, so the
+    /// bottom vertex stays at the bottom whichever way the view is rotated.
+    pub(crate) fn tile_half_diagonals(&self) -> (S::Vec2, S::Vec2) {
+        let (x_col, y_col): (S::Vec2, S::Vec2) = (self.basis.x_axis(), self.basis.y_axis());
+        let down: S::Vec2 = Self::screen_axes((x_col + y_col) * (S::HALF * self.zoom), self.y_axis, self.mirror_x);
+        let right: S::Vec2 = Self::screen_axes((x_col - y_col) * (S::HALF * self.zoom), self.y_axis, self.mirror_x);
 
-        Vec3::new(screen_vec_2d.x, screen_vec_2d.y, world_float_pos.z * self.z_scale)
+        (down, if self.mirror_x { -right } else { right })
     }
 }
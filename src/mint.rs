@@ -0,0 +1,81 @@
+use glam::{ IVec2, IVec3 };
+use mint::{ Vector2, Vector3 };
+use crate::{ IsoProjection, Scalar };
+
+impl<S: Scalar> IsoProjection<S>
+where
+    S::Vec2: From<Vector2<S>> + Into<Vector2<S>>,
+    S::Vec3: From<Vector3<S>> + Into<Vector3<S>>,
+{
+    /// Converts a grid position to its screen position like [`IsoProjection::world_to_screen`], using `mint` vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "mint")] {
+    /// use glam::IVec3;
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// let screen_pos: mint::Vector3<f32> = proj.world_to_screen_mint(mint::Vector3 { x: 1, y: 0, z: 1 });
+    ///
+    /// assert_eq!(screen_pos, proj.world_to_screen(IVec3::new(1, 0, 1)).into());
+    /// # }
+    /// ```
+    pub fn world_to_screen_mint(&self, world_pos: Vector3<i32>) -> Vector3<S> {
+        self.world_to_screen(IVec3::from(world_pos)).into()
+    }
+
+    /// Converts a screen position to its grid position like [`IsoProjection::screen_to_world`], using `mint` vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "mint")] {
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let pos: mint::Vector3<i32> = mint::Vector3 { x: -3, y: 8, z: 2 };
+    ///
+    /// assert_eq!(proj.screen_to_world_mint(proj.world_to_screen_mint(pos)), pos);
+    /// # }
+    /// ```
+    pub fn screen_to_world_mint(&self, screen_pos: Vector3<S>) -> Vector3<i32> {
+        self.screen_to_world(S::Vec3::from(screen_pos)).into()
+    }
+
+    /// Converts a grid x/y position to its screen x/y like [`IsoProjection::world_to_screen_2d`], using `mint` vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "mint")] {
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// assert_eq!(proj.world_to_screen_2d_mint(mint::Vector2 { x: 1, y: 0 }), mint::Vector2 { x: 14.0, y: 7.0 });
+    /// # }
+    /// ```
+    pub fn world_to_screen_2d_mint(&self, world_pos: Vector2<i32>) -> Vector2<S> {
+        self.world_to_screen_2d(IVec2::from(world_pos)).into()
+    }
+
+    /// Converts a screen x/y to its grid x/y position like [`IsoProjection::screen_to_world_2d`], using `mint` vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "mint")] {
+    /// use spriso::IsoProjection;
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// assert_eq!(proj.screen_to_world_2d_mint(mint::Vector2 { x: 14.0, y: 7.0 }), mint::Vector2 { x: 1, y: 0 });
+    /// # }
+    /// ```
+    pub fn screen_to_world_2d_mint(&self, screen: Vector2<S>) -> Vector2<i32> {
+        self.screen_to_world_2d(S::Vec2::from(screen)).into()
+    }
+}
@@ -0,0 +1,90 @@
+use core::ops::Neg;
+use glam::IVec2;
+
+/// Rotation of the world x/y around the origin before projection, in clockwise quarter turns.
+///
+/// Rotating only swaps and negates the basis vectors of the projection matrix, so
+/// every orientation round-trips exactly. World z is never affected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Orientation {
+    /// The unrotated view, with world +x running down-right on screen.
+    #[default]
+    Deg0,
+
+    /// The view turned clockwise a quarter, with world +x running down-left on screen.
+    Deg90,
+
+    /// The view turned half around, with world +x running up-left on screen.
+    Deg180,
+
+    /// The view turned counter-clockwise a quarter, with world +x running up-right on screen.
+    Deg270,
+}
+
+impl Orientation {
+    /// Returns the orientation turned a quarter clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spriso::Orientation;
+    ///
+    /// assert_eq!(Orientation::Deg0.rotate_cw(), Orientation::Deg90);
+    /// assert_eq!(Orientation::Deg270.rotate_cw(), Orientation::Deg0);
+    /// ```
+    pub fn rotate_cw(self) -> Self {
+        match self {
+            Self::Deg0 => Self::Deg90,
+            Self::Deg90 => Self::Deg180,
+            Self::Deg180 => Self::Deg270,
+            Self::Deg270 => Self::Deg0,
+        }
+    }
+
+    /// Returns the orientation turned a quarter counter-clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spriso::Orientation;
+    ///
+    /// assert_eq!(Orientation::Deg0.rotate_ccw(), Orientation::Deg270);
+    /// assert_eq!(Orientation::Deg90.rotate_ccw().rotate_cw(), Orientation::Deg90);
+    /// ```
+    pub fn rotate_ccw(self) -> Self {
+        match self {
+            Self::Deg0 => Self::Deg270,
+            Self::Deg90 => Self::Deg0,
+            Self::Deg180 => Self::Deg90,
+            Self::Deg270 => Self::Deg180,
+        }
+    }
+
+    /// Rotates a world x/y position by this orientation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::IVec2;
+    /// use spriso::Orientation;
+    ///
+    /// assert_eq!(Orientation::Deg90.rotate(IVec2::new(1, 0)), IVec2::new(0, 1));
+    /// assert_eq!(Orientation::Deg180.rotate(IVec2::new(2, 3)), IVec2::new(-2, -3));
+    /// ```
+    pub fn rotate(self, pos: IVec2) -> IVec2 {
+        let (x_axis, y_axis): (IVec2, IVec2) = self.rotate_axes(IVec2::X, IVec2::Y);
+
+        x_axis * pos.x + y_axis * pos.y
+    }
+
+    /// Returns the basis vectors of a projection after rotating the world by this orientation.
+    pub(crate) fn rotate_axes<V: Copy + Neg<Output = V>>(self, x_axis: V, y_axis: V) -> (V, V) {
+        match self {
+            Self::Deg0 => (x_axis, y_axis),
+            Self::Deg90 => (y_axis, -x_axis),
+            Self::Deg180 => (-x_axis, -y_axis),
+            Self::Deg270 => (-y_axis, x_axis),
+        }
+    }
+}
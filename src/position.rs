@@ -0,0 +1,193 @@
+use glam::{ DVec3, IVec3, Vec3 };
+use crate::{ IsoProjection, RoundingMode, Scalar };
+
+/// A grid position in world tiles.
+///
+/// Wrapping the raw `IVec3` lets the type system tell world and screen
+/// coordinates apart. It converts to and from `IVec3` with [`From`].
+///
+/// The typed conversions are [`IsoProjection::project`],
+/// [`IsoProjection::project_center`] and the `unproject` family. Methods working on
+/// the 2D screen plane, such as [`IsoProjection::tile_at_screen`], and the batch
+/// methods keep taking raw glam vectors, so convert with [`From`] at the boundary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct WorldPos(pub IVec3);
+
+/// A screen position in pixels, with the elevation offset in z.
+///
+/// Wrapping the raw vector lets the type system tell world and screen
+/// coordinates apart. It converts to and from the [`Scalar`]'s `Vec3` with [`From`].
+/// See [`WorldPos`] for the methods taking and returning it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScreenPos<S: Scalar = f32>(pub S::Vec3);
+
+impl From<IVec3> for WorldPos {
+    fn from(pos: IVec3) -> Self {
+        Self(pos)
+    }
+}
+
+impl From<WorldPos> for IVec3 {
+    fn from(pos: WorldPos) -> Self {
+        pos.0
+    }
+}
+
+impl<S: Scalar> ScreenPos<S> {
+    /// Wraps a raw screen vector.
+    pub fn new(pos: S::Vec3) -> Self {
+        Self(pos)
+    }
+
+    /// Returns the raw screen vector.
+    pub fn get(self) -> S::Vec3 {
+        self.0
+    }
+}
+
+impl From<Vec3> for ScreenPos<f32> {
+    fn from(pos: Vec3) -> Self {
+        Self(pos)
+    }
+}
+
+impl From<ScreenPos<f32>> for Vec3 {
+    fn from(pos: ScreenPos<f32>) -> Self {
+        pos.0
+    }
+}
+
+impl From<DVec3> for ScreenPos<f64> {
+    fn from(pos: DVec3) -> Self {
+        Self(pos)
+    }
+}
+
+impl From<ScreenPos<f64>> for DVec3 {
+    fn from(pos: ScreenPos<f64>) -> Self {
+        pos.0
+    }
+}
+
+impl<S: Scalar> IsoProjection<S> {
+    /// Converts a world position to its screen position like [`IsoProjection::world_to_screen`], using the typed wrappers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec3 };
+    /// use spriso::{ IsoProjection, ScreenPos, WorldPos };
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    ///
+    /// let screen_pos: ScreenPos = proj.project(IVec3::new(1, 0, 1).into());
+    ///
+    /// assert_eq!(Vec3::from(screen_pos), Vec3::new(14.0, 7.0, 14.0));
+    /// assert_eq!(proj.unproject(screen_pos), WorldPos(IVec3::new(1, 0, 1)));
+    /// ```
+    pub fn project(&self, world_pos: WorldPos) -> ScreenPos<S> {
+        ScreenPos(self.world_to_screen(world_pos.0))
+    }
+
+    /// Converts a world position to the screen position of its tile center like [`IsoProjection::world_to_screen_center`], using the typed wrappers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec3 };
+    /// use spriso::{ Anchor, IsoProjection, ScreenPos, WorldPos };
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>().with_anchor(Anchor::Top);
+    /// let pos: WorldPos = WorldPos(IVec3::new(1, 0, 1));
+    ///
+    /// assert_eq!(proj.project_center(pos), ScreenPos(Vec3::new(14.0, 7.0, 14.0)));
+    /// assert_eq!(proj.project(pos), ScreenPos(Vec3::new(14.0, 0.0, 14.0)));
+    /// ```
+    pub fn project_center(&self, world_pos: WorldPos) -> ScreenPos<S> {
+        ScreenPos(self.world_to_screen_center(world_pos.0))
+    }
+
+    /// Converts a screen position to its world position like [`IsoProjection::screen_to_world`], using the typed wrappers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ DVec3, IVec3 };
+    /// use spriso::{ IsoProjection, ScreenPos, WorldPos };
+    ///
+    /// let proj: IsoProjection<f64> = IsoProjection::from_dims(14.0, 14.0);
+    ///
+    /// let world_pos: WorldPos = proj.unproject(ScreenPos::new(DVec3::new(14.0, 7.0, 14.0)));
+    ///
+    /// assert_eq!(IVec3::from(world_pos), IVec3::new(1, 0, 1));
+    /// ```
+    pub fn unproject(&self, screen_pos: ScreenPos<S>) -> WorldPos {
+        WorldPos(self.screen_to_world(screen_pos.0))
+    }
+
+    /// Converts a screen position to its world position like [`IsoProjection::screen_to_world_with_rounding`], using the typed wrappers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::{ IVec3, Vec3 };
+    /// use spriso::{ IsoProjection, RoundingMode, ScreenPos, WorldPos };
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let screen_pos: ScreenPos = proj.world_to_screen_f(Vec3::new(-0.25, -0.25, 0.0)).into();
+    ///
+    /// assert_eq!(proj.unproject_with_rounding(screen_pos, RoundingMode::Nearest), WorldPos(IVec3::ZERO));
+    /// assert_eq!(proj.unproject_with_rounding(screen_pos, RoundingMode::Floor), WorldPos(IVec3::new(-1, -1, 0)));
+    /// ```
+    pub fn unproject_with_rounding(&self, screen_pos: ScreenPos<S>, mode: RoundingMode) -> WorldPos {
+        WorldPos(self.screen_to_world_with_rounding(screen_pos.0, mode))
+    }
+
+    /// Converts a screen position to its world position like [`IsoProjection::screen_to_world_checked`], using the typed wrappers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a `map_size` component isn't positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::IVec3;
+    /// use spriso::{ IsoProjection, WorldPos };
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let map_size: IVec3 = IVec3::new(8, 6, 2);
+    ///
+    /// let inside: WorldPos = WorldPos(IVec3::new(7, 5, 1));
+    /// let outside: WorldPos = WorldPos(IVec3::new(8, 5, 1));
+    ///
+    /// assert_eq!(proj.unproject_checked(proj.project(inside), map_size), Some(inside));
+    /// assert_eq!(proj.unproject_checked(proj.project(outside), map_size), None);
+    /// ```
+    pub fn unproject_checked(&self, screen_pos: ScreenPos<S>, map_size: IVec3) -> Option<WorldPos> {
+        self.screen_to_world_checked(screen_pos.0, map_size).map(WorldPos)
+    }
+
+    /// Converts a screen position to its world position like [`IsoProjection::screen_to_world_clamped`], using the typed wrappers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a `map_size` component isn't positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::IVec3;
+    /// use spriso::{ IsoProjection, WorldPos };
+    ///
+    /// let proj: IsoProjection = IsoProjection::new::<14, 14>();
+    /// let map_size: IVec3 = IVec3::new(8, 6, 2);
+    ///
+    /// let clamped: WorldPos = proj.unproject_clamped(proj.project(WorldPos(IVec3::new(-40, 90, 7))), map_size);
+    ///
+    /// assert_eq!(clamped, WorldPos(IVec3::new(0, 5, 1)));
+    /// ```
+    pub fn unproject_clamped(&self, screen_pos: ScreenPos<S>, map_size: IVec3) -> WorldPos {
+        WorldPos(self.screen_to_world_clamped(screen_pos.0, map_size))
+    }
+}
@@ -0,0 +1,15 @@
+/// How fractional diamond grid positions are snapped to integer grid positions.
+///
+/// Both modes are translation-invariant: every tile owns a half-open unit cell of
+/// the same shape, so negative positions snap like positive ones instead of towards zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RoundingMode {
+    /// Rounds to the nearest tile with `floor(x + 0.5)`, so tile `n` owns `[n - 0.5, n + 0.5)`
+    /// and `-0.5` snaps to `0` while `-0.6` snaps to `-1`.
+    #[default]
+    Nearest,
+
+    /// Rounds down with `floor(x)`, so tile `n` owns `[n, n + 1)` and `-0.25` snaps to
+    /// `-1`. This picks the cell between tile centers that a point falls into.
+    Floor,
+}
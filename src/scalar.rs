@@ -0,0 +1,299 @@
+use core::fmt::Debug;
+use core::ops::{ Add, Div, Mul, Neg, Sub };
+use glam::{ Affine2, DAffine2, DMat2, DMat3, DVec2, DVec3, I64Vec3, IVec2, IVec3, Mat2, Mat3, Vec2, Vec3 };
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// Floating point scalar that an [`IsoProjection`](crate::IsoProjection) computes in.
+///
+/// Implemented for `f32` (using `Vec2`, `Vec3`, `Mat2`, `Mat3` and `Affine2`) and `f64`
+/// (using `DVec2`, `DVec3`, `DMat2`, `DMat3` and `DAffine2`). The trait is sealed, so it can't be implemented outside this crate.
+pub trait Scalar:
+    Copy + Debug + PartialEq + PartialOrd
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+    + sealed::Sealed
+{
+    /// The two component glam vector of this scalar.
+    type Vec2: Vector2<Self>;
+
+    /// The three component glam vector of this scalar.
+    type Vec3: Vector3<Self>;
+
+    /// The 2x2 glam matrix of this scalar.
+    type Mat2: Matrix2<Self, Self::Vec2>;
+
+    /// The 3x3 glam matrix of this scalar.
+    type Mat3: Copy + Debug + PartialEq;
+
+    /// The 2D affine glam transform of this scalar.
+    type Affine2: Affine<Self::Vec2, Self::Mat2, Self::Mat3>;
+
+    /// Zero.
+    const ZERO: Self;
+
+    /// One.
+    const ONE: Self;
+
+    /// One half.
+    const HALF: Self;
+
+    /// Converts an integer world coordinate to this scalar.
+    fn from_i32(value: i32) -> Self;
+
+    /// Converts a 64-bit integer world coordinate to this scalar, rounding to the nearest representable value.
+    fn from_i64(value: i64) -> Self;
+
+    /// Casts to an integer world coordinate, truncating toward zero and saturating.
+    fn as_i32(self) -> i32;
+
+    /// Returns the absolute value.
+    fn abs(self) -> Self;
+
+    /// Returns `true` if the value is neither infinite nor NaN.
+    fn is_finite(self) -> bool;
+}
+
+/// Two component glam vector generic over its [`Scalar`].
+pub trait Vector2<S>:
+    Copy + Debug + PartialEq
+    + Add<Output = Self> + Sub<Output = Self> + Mul<S, Output = Self> + Neg<Output = Self>
+{
+    /// Creates a vector from its components.
+    fn new(x: S, y: S) -> Self;
+
+    /// Returns the x component.
+    fn x(self) -> S;
+
+    /// Returns the y component.
+    fn y(self) -> S;
+
+    /// Returns the component-wise minimum of `self` and `rhs`.
+    fn min(self, rhs: Self) -> Self;
+
+    /// Returns the component-wise maximum of `self` and `rhs`.
+    fn max(self, rhs: Self) -> Self;
+
+    /// Returns the Euclidean length of the vector.
+    fn length(self) -> S;
+
+    /// Rounds each component down to the nearest integer.
+    fn floor(self) -> Self;
+
+    /// Rounds each component up to the nearest integer.
+    fn ceil(self) -> Self;
+
+    /// Casts each component to an integer, truncating toward zero and saturating.
+    fn as_ivec2(self) -> IVec2;
+}
+
+/// Three component glam vector generic over its [`Scalar`].
+pub trait Vector3<S>:
+    Copy + Debug + PartialEq
+    + Add<Output = Self> + Sub<Output = Self> + Mul<S, Output = Self> + Neg<Output = Self>
+{
+    /// Creates a vector from its components.
+    fn new(x: S, y: S, z: S) -> Self;
+
+    /// Returns the x component.
+    fn x(self) -> S;
+
+    /// Returns the y component.
+    fn y(self) -> S;
+
+    /// Returns the z component.
+    fn z(self) -> S;
+
+    /// Rounds each component down to the nearest integer.
+    fn floor(self) -> Self;
+
+    /// Casts each component to an integer, truncating toward zero and saturating.
+    fn as_ivec3(self) -> IVec3;
+
+    /// Casts each component to a 64-bit integer, truncating toward zero and saturating.
+    fn as_i64vec3(self) -> I64Vec3;
+}
+
+/// 2x2 glam matrix generic over its [`Scalar`] and vector type.
+pub trait Matrix2<S, V>: Copy + Debug + PartialEq + Mul<V, Output = V> {
+    /// Creates a matrix from its two column vectors.
+    fn from_cols(x_axis: V, y_axis: V) -> Self;
+
+    /// Returns the first column.
+    fn x_axis(&self) -> V;
+
+    /// Returns the second column.
+    fn y_axis(&self) -> V;
+
+    /// Returns the determinant of the matrix.
+    fn determinant(&self) -> S;
+
+    /// Returns the inverse of the matrix.
+    fn inverse(&self) -> Self;
+}
+
+/// 2D affine glam transform generic over its vector and matrix types.
+pub trait Affine<V, M, M3>: Copy + Debug + PartialEq {
+    /// Creates a transform from a linear 2x2 part and a translation.
+    fn from_mat2_translation(matrix2: M, translation: V) -> Self;
+
+    /// Converts the transform to a 3x3 matrix acting on homogeneous coordinates.
+    fn to_mat3(self) -> M3;
+
+    /// Returns the inverse transform.
+    fn inverse(&self) -> Self;
+
+    /// Transforms a point, applying the linear part and the translation.
+    fn transform_point2(&self, point: V) -> V;
+}
+
+macro_rules! impl_scalar {
+    ($t:ty, $vec2:ty, $vec3:ty, $mat2:ty, $mat3:ty, $affine2:ty) => {
+        impl Scalar for $t {
+            type Vec2 = $vec2;
+            type Vec3 = $vec3;
+            type Mat2 = $mat2;
+            type Mat3 = $mat3;
+            type Affine2 = $affine2;
+
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const HALF: Self = 0.5;
+
+            fn from_i32(value: i32) -> Self {
+                value as $t
+            }
+
+            fn from_i64(value: i64) -> Self {
+                value as $t
+            }
+
+            fn as_i32(self) -> i32 {
+                self as i32
+            }
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn is_finite(self) -> bool {
+                <$t>::is_finite(self)
+            }
+        }
+
+        impl Vector2<$t> for $vec2 {
+            fn new(x: $t, y: $t) -> Self {
+                <$vec2>::new(x, y)
+            }
+
+            fn x(self) -> $t {
+                self.x
+            }
+
+            fn y(self) -> $t {
+                self.y
+            }
+
+            fn min(self, rhs: Self) -> Self {
+                <$vec2>::min(self, rhs)
+            }
+
+            fn max(self, rhs: Self) -> Self {
+                <$vec2>::max(self, rhs)
+            }
+
+            fn length(self) -> $t {
+                <$vec2>::length(self)
+            }
+
+            fn floor(self) -> Self {
+                <$vec2>::floor(self)
+            }
+
+            fn ceil(self) -> Self {
+                <$vec2>::ceil(self)
+            }
+
+            fn as_ivec2(self) -> IVec2 {
+                <$vec2>::as_ivec2(&self)
+            }
+        }
+
+        impl Vector3<$t> for $vec3 {
+            fn new(x: $t, y: $t, z: $t) -> Self {
+                <$vec3>::new(x, y, z)
+            }
+
+            fn x(self) -> $t {
+                self.x
+            }
+
+            fn y(self) -> $t {
+                self.y
+            }
+
+            fn z(self) -> $t {
+                self.z
+            }
+
+            fn floor(self) -> Self {
+                <$vec3>::floor(self)
+            }
+
+            fn as_ivec3(self) -> IVec3 {
+                <$vec3>::as_ivec3(&self)
+            }
+
+            fn as_i64vec3(self) -> I64Vec3 {
+                <$vec3>::as_i64vec3(&self)
+            }
+        }
+
+        impl Matrix2<$t, $vec2> for $mat2 {
+            fn from_cols(x_axis: $vec2, y_axis: $vec2) -> Self {
+                <$mat2>::from_cols(x_axis, y_axis)
+            }
+
+            fn x_axis(&self) -> $vec2 {
+                self.x_axis
+            }
+
+            fn y_axis(&self) -> $vec2 {
+                self.y_axis
+            }
+
+            fn determinant(&self) -> $t {
+                <$mat2>::determinant(self)
+            }
+
+            fn inverse(&self) -> Self {
+                <$mat2>::inverse(self)
+            }
+        }
+
+        impl Affine<$vec2, $mat2, $mat3> for $affine2 {
+            fn from_mat2_translation(matrix2: $mat2, translation: $vec2) -> Self {
+                <$affine2>::from_mat2_translation(matrix2, translation)
+            }
+
+            fn to_mat3(self) -> $mat3 {
+                <$mat3>::from(self)
+            }
+
+            fn inverse(&self) -> Self {
+                <$affine2>::inverse(self)
+            }
+
+            fn transform_point2(&self, point: $vec2) -> $vec2 {
+                <$affine2>::transform_point2(self, point)
+            }
+        }
+    };
+}
+
+impl_scalar!(f32, Vec2, Vec3, Mat2, Mat3, Affine2);
+impl_scalar!(f64, DVec2, DVec3, DMat2, DMat3, DAffine2);
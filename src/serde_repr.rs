@@ -0,0 +1,123 @@
+use serde::{ de, Deserialize, Deserializer, Serialize, Serializer };
+use crate::{ Anchor, IsoProjection, IsoProjectionBuilder, Layout, Matrix2, Orientation, ProjectionError, Scalar, Vector2, YAxis };
+
+/// Version of [`ProjectionRepr`] written by this crate.
+///
+/// Payloads without a version are from before the tag was added and are read as version 0,
+/// which stores the same source parameters with every field after `z_scale` optional.
+const FORMAT_VERSION: u32 = 1;
+
+/// Serialized form of an [`IsoProjection`].
+///
+/// Only the source parameters are stored. The matrices, inverse matrix and inverse z scale are
+/// rebuilt on deserialize, so a hand-edited file can never hold an inconsistent inverse and
+/// saves keep loading when the derived state changes.
+#[derive(Serialize, Deserialize)]
+struct ProjectionRepr<S: Scalar> {
+    #[serde(default)]
+    version: u32,
+    half_tw: S,
+    half_th: S,
+    /// Only stored for a basis given to [`IsoProjection::from_basis`] that the half-tile size doesn't describe.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    basis: Option<[S::Vec2; 2]>,
+    z_scale: S,
+    #[serde(default = "default_origin::<S>")]
+    origin: S::Vec2,
+    #[serde(default = "default_one::<S>")]
+    parallax: S,
+    #[serde(default = "default_zero::<S>")]
+    layer_offset: S,
+    #[serde(default = "default_one::<S>")]
+    zoom: S,
+    #[serde(default)]
+    layout: Layout,
+    #[serde(default)]
+    orientation: Orientation,
+    #[serde(default)]
+    y_axis: YAxis,
+    #[serde(default)]
+    mirror_x: bool,
+    #[serde(default)]
+    anchor: Anchor,
+}
+
+fn default_origin<S: Scalar>() -> S::Vec2 {
+    S::Vec2::new(S::ZERO, S::ZERO)
+}
+
+fn default_zero<S: Scalar>() -> S {
+    S::ZERO
+}
+
+fn default_one<S: Scalar>() -> S {
+    S::ONE
+}
+
+impl<S: Scalar> From<IsoProjection<S>> for ProjectionRepr<S> {
+    fn from(proj: IsoProjection<S>) -> Self {
+        Self {
+            version: FORMAT_VERSION,
+            half_tw: proj.half_tw,
+            half_th: proj.half_th,
+            basis: (proj.basis != IsoProjection::tile_basis(proj.half_tw, proj.half_th))
+                .then(|| [proj.basis.x_axis(), proj.basis.y_axis()]),
+            z_scale: proj.z_scale,
+            origin: proj.origin,
+            parallax: proj.parallax,
+            layer_offset: proj.layer_offset,
+            zoom: proj.zoom,
+            layout: proj.layout,
+            orientation: proj.orientation,
+            y_axis: proj.y_axis,
+            mirror_x: proj.mirror_x,
+            anchor: proj.anchor,
+        }
+    }
+}
+
+impl<S: Scalar> TryFrom<ProjectionRepr<S>> for IsoProjection<S> {
+    type Error = ProjectionError;
+
+    fn try_from(repr: ProjectionRepr<S>) -> Result<Self, Self::Error> {
+        let builder: IsoProjectionBuilder<S> = match repr.basis {
+            Some([x_axis, y_axis]) => IsoProjection::builder().basis(x_axis, y_axis),
+            None => IsoProjection::builder().half_tile(repr.half_tw, repr.half_th),
+        };
+
+        builder
+            .z_scale(repr.z_scale)
+            .origin(repr.origin)
+            .parallax(repr.parallax)
+            .layer_offset(repr.layer_offset)
+            .zoom(repr.zoom)
+            .layout(repr.layout)
+            .orientation(repr.orientation)
+            .y_axis(repr.y_axis)
+            .mirror_x(repr.mirror_x)
+            .anchor(repr.anchor)
+            .build()
+    }
+}
+
+impl<S: Scalar + Serialize> Serialize for IsoProjection<S> where S::Vec2: Serialize {
+    fn serialize<R: Serializer>(&self, serializer: R) -> Result<R::Ok, R::Error> {
+        ProjectionRepr::from(*self).serialize(serializer)
+    }
+}
+
+impl<'de, S: Scalar + Deserialize<'de>> Deserialize<'de> for IsoProjection<S> where S::Vec2: Deserialize<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr: ProjectionRepr<S> = ProjectionRepr::deserialize(deserializer)?;
+
+        if repr.version > FORMAT_VERSION {
+            return Err(de::Error::custom(format_args!(
+                "projection format version {} is newer than the supported version {}",
+                repr.version,
+                FORMAT_VERSION
+            )));
+        }
+
+        IsoProjection::try_from(repr).map_err(de::Error::custom)
+    }
+}
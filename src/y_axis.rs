@@ -0,0 +1,27 @@
+use core::ops::Neg;
+
+/// Which way screen +y points.
+///
+/// The map looks the same either way: with [`YAxis::Up`] every screen y is negated,
+/// so the picture isn't mirrored in renderers where +y points up. Elevated tiles
+/// are always drawn higher on screen, towards -y for [`YAxis::Down`] and +y for [`YAxis::Up`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum YAxis {
+    /// Screen +y points down, as in most 2D canvases and window coordinates.
+    #[default]
+    Down,
+
+    /// Screen +y points up, as in OpenGL style and most game engine world coordinates.
+    Up,
+}
+
+impl YAxis {
+    /// Converts a y offset measured downwards on screen to point along this axis.
+    pub(crate) fn orient<V: Neg<Output = V>>(self, y: V) -> V {
+        match self {
+            Self::Down => y,
+            Self::Up => -y,
+        }
+    }
+}